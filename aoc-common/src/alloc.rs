@@ -0,0 +1,126 @@
+//! Allocation counting for `bench`. Building with the `count-allocations`
+//! feature installs [`CountingAllocator`] as the global allocator, which passes
+//! everything on to the system allocator while keeping count.
+//!
+//! The counters are process-wide, so a measurement includes whatever other
+//! threads allocate meanwhile; `bench` runs one stage at a time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is passed on unchanged to `System`; the bookkeeping
+// around it never allocates.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::freed(layout.size());
+    }
+
+    /// Counts as a fresh allocation of `new_size` bytes, as growing a `Vec`
+    /// may well copy it.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+        new
+    }
+}
+
+/// What a stage allocated: the number of allocations, their total size, and
+/// the most bytes it held at once.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    pub peak: usize,
+}
+
+/// Whether the counting allocator is installed, and [`measure`] means anything.
+pub fn enabled() -> bool {
+    cfg!(feature = "count-allocations")
+}
+
+/// Runs `f`, counting its allocations. The peak is taken above what was live
+/// when `f` started.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let value = f();
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (value, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_and_peak() {
+        let small = Layout::array::<u64>(8).unwrap();
+        let large = Layout::array::<u64>(100).unwrap();
+        let ((), allocations) = measure(|| unsafe {
+            let a = CountingAllocator.alloc(small);
+            CountingAllocator.dealloc(a, small);
+            let b = CountingAllocator.alloc(large);
+            let b = CountingAllocator.realloc(b, large, 1600);
+            CountingAllocator.dealloc(b, Layout::array::<u64>(200).unwrap());
+        });
+        // Other tests allocate meanwhile when the allocator is installed.
+        assert!(allocations.count >= 3);
+        assert!(allocations.bytes >= 64 + 800 + 1600);
+        assert!(allocations.peak >= 1600);
+        if !enabled() {
+            assert_eq!(allocations.count, 3);
+            assert_eq!(allocations.bytes, 64 + 800 + 1600);
+            assert_eq!(allocations.peak, 1600);
+        }
+    }
+}
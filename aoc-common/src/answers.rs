@@ -0,0 +1,234 @@
+//! Expected answers, read from `input/{YEAR}/answers.txt` when it exists and
+//! from the copy bundled with the year's crate otherwise.
+//!
+//! Each non-comment line holds `day part variant answer`, where the variant
+//! is `input` for the puzzle input itself, optionally followed by the day
+//! parameters the answer was computed with as `name=value`. An answer of `?`
+//! is a placeholder for one not known yet and is ignored.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::input::{self, InputError};
+use crate::solution::{Params, Year};
+
+pub const INPUT_VARIANT: &str = "input";
+pub const UNKNOWN_ANSWER: &str = "?";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub variant: String,
+    pub answer: String,
+    pub params: Params,
+}
+
+/// The entry as a line of the answers file.
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02} {} {} {}",
+            self.day, self.part, self.variant, self.answer
+        )?;
+        if !self.params.is_empty() {
+            write!(f, " {}", self.params)?;
+        }
+        Ok(())
+    }
+}
+
+impl Entry {
+    /// A test function name such as `day20_part_1_example_min_dt_64`.
+    pub fn test_name(&self) -> String {
+        let mut name = format!("day{:02}_part_{}_{}", self.day, self.part, self.variant);
+        for (param, value) in self.params.iter() {
+            name.push_str(&format!("_{param}_{value}"));
+        }
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Input(InputError),
+    Syntax { source: String, line: usize },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Input(err) => write!(f, "{err}"),
+            AnswersError::Syntax { source, line } => {
+                write!(
+                    f,
+                    "{source}:{line}: expected `day part variant answer [name=value]...`"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    entries: Vec<Entry>,
+}
+
+impl Answers {
+    pub fn load(year: &Year) -> Result<Answers, AnswersError> {
+        let path = path(year.year);
+        if path.is_file() {
+            let text = input::read_path(&path).map_err(AnswersError::Input)?;
+            Answers::parse(&text, &path.display().to_string())
+        } else {
+            Answers::parse(year.answers, &format!("aoc{}/answers.txt", year.year))
+        }
+    }
+
+    pub fn parse(text: &str, source: &str) -> Result<Answers, AnswersError> {
+        let mut entries = Vec::new();
+        for (k, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let syntax_error = || AnswersError::Syntax {
+                source: source.to_string(),
+                line: k + 1,
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, variant, answer, ref params @ ..] = fields[..] else {
+                return Err(syntax_error());
+            };
+            let mut entry = Entry {
+                day: day.parse().map_err(|_| syntax_error())?,
+                part: part.parse().map_err(|_| syntax_error())?,
+                variant: variant.to_string(),
+                answer: answer.to_string(),
+                params: Params::new(),
+            };
+            for param in params {
+                let (name, value) = param
+                    .split_once('=')
+                    .and_then(|(name, value)| Some((name, value.parse().ok()?)))
+                    .ok_or_else(syntax_error)?;
+                entry.params.set(name, value);
+            }
+            if entry.answer != UNKNOWN_ANSWER {
+                entries.push(entry);
+            }
+        }
+        Ok(Answers { entries })
+    }
+
+    /// The answer for the default parameters.
+    pub fn get(&self, day: u8, part: u8, variant: Option<&str>) -> Option<&str> {
+        self.matching(day, part, variant)
+            .find(|e| e.params.is_empty())
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Every answer for `variant`, whatever the parameters.
+    pub fn matching(
+        &self,
+        day: u8,
+        part: u8,
+        variant: Option<&str>,
+    ) -> impl Iterator<Item = &Entry> {
+        let variant = variant.unwrap_or(INPUT_VARIANT);
+        self.entries
+            .iter()
+            .filter(move |e| e.day == day && e.part == part && e.variant == variant)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
+
+/// Rust source with one `#[test]` per entry, each checking its own line with
+/// `check`, a function in scope taking the line, e.g.
+/// `crate::solution::check_entry`. Years include this from their build script
+/// so that every answer, example or not, is a separate test case.
+pub fn generate_tests(answers: &Answers, check: &str) -> String {
+    let mut source = String::new();
+    let mut seen = Vec::new();
+    for entry in answers.entries() {
+        let mut name = entry.test_name();
+        let repeats = seen.iter().filter(|n| **n == name).count();
+        seen.push(name.clone());
+        if repeats > 0 {
+            name.push_str(&format!("_{}", repeats + 1));
+        }
+        source.push_str(&format!(
+            "#[test]\nfn {name}() {{\n    {check}({:?});\n}}\n\n",
+            entry.to_string()
+        ));
+    }
+    source
+}
+
+/// Location of the answers file in the input tree.
+pub fn path(year: &str) -> PathBuf {
+    input::root().join(year).join("answers.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_entries() {
+        let answers = Answers::parse(
+            "# comment\n06 1 input 5208\n18 2 example 6,1 n_fallen=12\n06 1 example 41\n",
+            "t",
+        )
+        .expect("valid answers");
+        assert_eq!(answers.get(6, 1, None), Some("5208"));
+        assert_eq!(answers.get(6, 1, Some("example")), Some("41"));
+        assert_eq!(answers.get(18, 2, Some("example")), None);
+        let entry = answers.matching(18, 2, Some("example")).next().unwrap();
+        assert_eq!(entry.answer, "6,1");
+        assert_eq!(entry.params, Params::new().with("n_fallen", 12));
+    }
+
+    #[test]
+    fn syntax_error_has_line() {
+        let err = Answers::parse("06 1 input 5208\n06 2\n", "t").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "t:2: expected `day part variant answer [name=value]...`"
+        );
+        assert!(Answers::parse("20 1 example 5 min_dt\n", "t").is_err());
+        let placeholder = Answers::parse("07 1 input ?\n", "t").unwrap();
+        assert_eq!(placeholder.get(7, 1, None), None);
+    }
+
+    #[test]
+    fn generated_tests() {
+        let answers = Answers::parse(
+            "20 1 example 5 min_dt=20\n3 2 example2 48\n3 2 example2 48\n",
+            "t",
+        )
+        .unwrap();
+        assert_eq!(answers.entries()[0].to_string(), "20 1 example 5 min_dt=20");
+        let source = generate_tests(&answers, "check");
+        assert_eq!(
+            source
+                .lines()
+                .filter(|l| l.starts_with("fn "))
+                .collect::<Vec<_>>(),
+            [
+                "fn day20_part_1_example_min_dt_20() {",
+                "fn day03_part_2_example2() {",
+                "fn day03_part_2_example2_2() {",
+            ]
+        );
+        assert!(source.contains("    check(\"03 2 example2 48\");\n"));
+    }
+}
@@ -0,0 +1,217 @@
+//! Timing of the parse and solve stages of a day.
+
+use std::fmt;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::alloc::{self, Allocations};
+use crate::error::Result;
+use crate::json::Json;
+use crate::solution::{Day, Params};
+
+pub const DEFAULT_WARMUP: usize = 1;
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Stage, String> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s
+                .strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Stage::Part)
+                .ok_or_else(|| format!("unknown stage: {s}")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub year: String,
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+    /// Counted on the stage's first run, when built with `count-allocations`.
+    pub allocations: Option<Allocations>,
+}
+
+impl Measurement {
+    pub fn to_json(&self) -> Json {
+        let nanos = |d: Duration| Json::from(d.as_nanos() as u64);
+        let mut fields = vec![
+            ("year", self.year.as_str().into()),
+            ("day", self.day.into()),
+            ("stage", self.stage.to_string().into()),
+            ("iterations", self.stats.iterations.into()),
+            ("min_ns", nanos(self.stats.min)),
+            ("median_ns", nanos(self.stats.median)),
+            ("mean_ns", nanos(self.stats.mean)),
+            ("stddev_ns", nanos(self.stats.stddev)),
+        ];
+        if let Some(allocations) = self.allocations {
+            fields.extend([
+                ("allocations", allocations.count.into()),
+                ("allocated_bytes", allocations.bytes.into()),
+                ("peak_bytes", allocations.peak.into()),
+            ]);
+        }
+        Json::object(fields)
+    }
+}
+
+/// Times `f` over `iterations` runs after `warmup` untimed ones. There must
+/// be at least one iteration.
+pub fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    assert!(iterations > 0, "no iterations to time");
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    Stats::from_samples(&samples)
+}
+
+/// Times parsing `text` and then each of `parts` on a single parsed input.
+/// Each stage is run once untimed first, so errors are reported before any
+/// timing starts; that run is also the one whose allocations are counted.
+pub fn bench_day(
+    day: &Day,
+    text: &str,
+    parts: &[u8],
+    params: &Params,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Vec<Measurement>> {
+    let counted = |allocations| alloc::enabled().then_some(allocations);
+    let (parsed, allocations) = alloc::measure(|| day.parse(text));
+    let parsed = parsed?;
+    let mut measurements = vec![Measurement {
+        year: day.year.to_string(),
+        day: day.day,
+        stage: Stage::Parse,
+        stats: measure(warmup, iterations, || day.parse(text)),
+        allocations: counted(allocations),
+    }];
+    for &part in parts {
+        let (answer, allocations) = alloc::measure(|| day.solve(&parsed, part, params));
+        answer?;
+        measurements.push(Measurement {
+            year: day.year.to_string(),
+            day: day.day,
+            stage: Stage::Part(part),
+            stats: measure(warmup, iterations, || day.solve(&parsed, part, params)),
+            allocations: counted(allocations),
+        });
+    }
+    Ok(measurements)
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes < 1 << 10 {
+        format!("{bytes} B")
+    } else if bytes < 1 << 20 {
+        format!("{:.2} KiB", bytes as f64 / (1u64 << 10) as f64)
+    } else if bytes < 1 << 30 {
+        format!("{:.2} MiB", bytes as f64 / (1u64 << 20) as f64)
+    } else {
+        format!("{:.2} GiB", bytes as f64 / (1u64 << 30) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn durations_are_readable() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::from_secs(3)), "3.00 s");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00 MiB");
+    }
+}
@@ -0,0 +1,232 @@
+//! Differential testing: solving generated inputs with both a day's solver and
+//! its brute-force reference, and shrinking any input they disagree on to a
+//! minimal one that still shows the difference.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::error::Result;
+use crate::solution::{Answer, Day, Params};
+
+/// Small inputs keep the references fast.
+pub const DEFAULT_SIZE: usize = 6;
+pub const DEFAULT_INPUTS: u64 = 100;
+
+/// A part on which the solver and the reference answer differently. Two
+/// errors count as the same answer, whatever they say.
+#[derive(Clone, Debug)]
+pub struct Disagreement {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub reference: Result<Answer>,
+}
+
+/// A generated input, shrunk, on which a day's solver and reference disagree.
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub disagreement: Disagreement,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |answer: &Result<Answer>| match answer {
+            Ok(answer) => answer.to_string(),
+            Err(err) => format!("error: {err}"),
+        };
+        write!(
+            f,
+            "part {} disagrees on seed {} size {}, shrunk to:\n{}solver:    {}\nreference: {}",
+            self.disagreement.part,
+            self.seed,
+            self.size,
+            self.input,
+            show(&self.disagreement.answer),
+            show(&self.disagreement.reference),
+        )
+    }
+}
+
+/// The number of parts that had a reference for `input`, and the first one
+/// on which the solver disagrees with it. Inputs that fail to parse or break
+/// the solver's assumptions have nothing to compare.
+pub fn compare(day: &Day, input: &str, params: &Params) -> (usize, Option<Disagreement>) {
+    let Ok(parsed) = day.parse(input) else {
+        return (0, None);
+    };
+    if !day.validate(input, params).is_empty() {
+        return (0, None);
+    }
+    let mut compared = 0;
+    for part in 1..=day.parts {
+        let Some(reference) = day.reference(&parsed, part, params) else {
+            continue;
+        };
+        compared += 1;
+        let answer = day.solve(&parsed, part, params);
+        if answer.as_ref().ok() != reference.as_ref().ok() {
+            return (
+                compared,
+                Some(Disagreement {
+                    part,
+                    answer,
+                    reference,
+                }),
+            );
+        }
+    }
+    (compared, None)
+}
+
+/// Compares the solver with the reference on the inputs generated from
+/// `seeds`, returning the number of parts compared, or the first mismatch,
+/// shrunk.
+pub fn check(
+    day: &Day,
+    seeds: Range<u64>,
+    size: usize,
+    params: &Params,
+) -> Result<usize, Box<Mismatch>> {
+    let mut compared = 0;
+    for seed in seeds {
+        let Some(input) = day.generate(seed, size) else {
+            break;
+        };
+        match compare(day, &input, params) {
+            (parts, None) => compared += parts,
+            (_, Some(disagreement)) => {
+                return Err(Box::new(shrink(day, seed, size, params, disagreement)));
+            }
+        }
+    }
+    Ok(compared)
+}
+
+/// Looks for the smallest generated input of the same seed that still
+/// disagrees on the same part, then drops runs of its lines for as long as
+/// the disagreement remains.
+pub fn shrink(
+    day: &Day,
+    seed: u64,
+    size: usize,
+    params: &Params,
+    disagreement: Disagreement,
+) -> Mismatch {
+    let part = disagreement.part;
+    let disagrees = |input: &str| match compare(day, input, params) {
+        (_, Some(found)) if found.part == part => Some(found),
+        _ => None,
+    };
+
+    let mut mismatch = (1..size)
+        .find_map(|smaller| {
+            let input = day.generate(seed, smaller)?;
+            let disagreement = disagrees(&input)?;
+            Some(Mismatch {
+                seed,
+                size: smaller,
+                input,
+                disagreement,
+            })
+        })
+        .unwrap_or_else(|| Mismatch {
+            seed,
+            size,
+            input: day.generate(seed, size).unwrap_or_default(),
+            disagreement,
+        });
+
+    let input = mismatch.input.clone();
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let mut run = lines.len() / 2;
+    while run > 0 {
+        let mut start = 0;
+        let mut dropped = false;
+        while start < lines.len() {
+            let mut candidate = lines[..start].to_vec();
+            candidate.extend(&lines[(start + run).min(lines.len())..]);
+            let text = candidate
+                .iter()
+                .map(|line| format!("{line}\n"))
+                .collect::<String>();
+            if !candidate.is_empty()
+                && let Some(disagreement) = disagrees(&text)
+            {
+                lines = candidate;
+                mismatch.input = text;
+                mismatch.disagreement = disagreement;
+                dropped = true;
+            } else {
+                start += run;
+            }
+        }
+        if !dropped {
+            run /= 2;
+        }
+    }
+    mismatch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+
+    /// Sums the numbers, but its part 1 forgets about those above 9.
+    struct Digits;
+
+    impl Solution for Digits {
+        const DAY: u8 = 1;
+        type Input = Vec<u64>;
+        type Output1 = u64;
+        type Output2 = u64;
+
+        fn parse(input: &str) -> Result<Vec<u64>> {
+            input
+                .split_whitespace()
+                .map(|n| crate::error::parse_at(input, n))
+                .collect()
+        }
+
+        fn generate(seed: u64, size: usize) -> Option<String> {
+            Some(
+                (0..size as u64)
+                    .map(|k| format!("{}\n", (seed + 3 * k) % 13))
+                    .collect(),
+            )
+        }
+
+        fn part_1(input: &Vec<u64>, _params: &Params) -> Result<u64> {
+            Ok(input.iter().filter(|&&n| n < 10).sum())
+        }
+
+        fn part_2(input: &Vec<u64>, _params: &Params) -> Result<u64> {
+            Ok(input.len() as u64)
+        }
+
+        fn reference_1(input: &Vec<u64>, _params: &Params) -> Option<Result<u64>> {
+            Some(Ok(input.iter().sum()))
+        }
+    }
+
+    static DIGITS: Day = Day::of::<Digits>("2024");
+
+    #[test]
+    fn agreeing_inputs_count_the_compared_parts() {
+        assert_eq!(compare(&DIGITS, "1\n2\n", &Params::new()).0, 1);
+        assert_eq!(compare(&DIGITS, "not a number\n", &Params::new()).0, 0);
+        assert_eq!(check(&DIGITS, 0..5, 2, &Params::new()).unwrap(), 5);
+    }
+
+    #[test]
+    fn mismatches_shrink_to_the_offending_line() {
+        // Seed 1 first reaches 10 on its fourth line.
+        let mismatch = check(&DIGITS, 1..2, 8, &Params::new()).unwrap_err();
+        assert_eq!((mismatch.seed, mismatch.size), (1, 4));
+        assert_eq!(mismatch.input, "10\n");
+        assert_eq!(mismatch.disagreement.part, 1);
+        assert_eq!(mismatch.disagreement.reference, Ok(Answer::Unsigned(10)));
+    }
+}
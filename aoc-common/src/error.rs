@@ -0,0 +1,183 @@
+//! The error returned by parsers and solvers, located in the input when
+//! possible so that a malformed or wrong-day input points at the culprit.
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::input::InputError;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Error {
+    pub message: String,
+    pub file: Option<String>,
+    /// 1-based line and column.
+    pub position: Option<(usize, usize)>,
+    /// The offending line of the input.
+    pub source_line: Option<String>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Error {
+        Error {
+            message: message.into(),
+            ..Error::default()
+        }
+    }
+
+    /// Points the error at `fragment`, which must be a slice of `input`.
+    pub fn at(mut self, input: &str, fragment: &str) -> Error {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+        if offset > input.len()
+            || input.len() - offset < fragment.len()
+            || !input.is_char_boundary(offset)
+        {
+            return self;
+        }
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |k| k + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |k| offset + k);
+        self.position = Some((
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        ));
+        self.source_line = Some(input[line_start..line_end].to_string());
+        self
+    }
+
+    /// Points the error at a 1-based line and column of `input`.
+    pub fn at_position(mut self, input: &str, line: usize, column: usize) -> Error {
+        self.position = Some((line, column));
+        self.source_line = input.lines().nth(line - 1).map(str::to_string);
+        self
+    }
+
+    /// Moves an error located in `part`, a slice of `input`, to the same
+    /// place in `input`.
+    pub fn within(self, input: &str, part: &str) -> Error {
+        let Some((line, column)) = self.position else {
+            return self;
+        };
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        if offset > input.len() {
+            return self;
+        }
+        let before = &input[..offset];
+        let lines_before = before.matches('\n').count();
+        let column = if line == 1 {
+            column
+                + before[before.rfind('\n').map_or(0, |k| k + 1)..]
+                    .chars()
+                    .count()
+        } else {
+            column
+        };
+        self.at_position(input, line + lines_before, column)
+    }
+
+    /// Names the file the input came from, unless one is already set.
+    pub fn in_file(mut self, file: &Path) -> Error {
+        if self.file.is_none() {
+            self.file = Some(file.display().to_string());
+        }
+        self
+    }
+}
+
+/// `file:line:column: message`. The alternate form `{:#}` adds the source
+/// line with a marker under the column.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.position) {
+            (Some(file), Some((line, column))) => write!(f, "{file}:{line}:{column}: ")?,
+            (None, Some((line, column))) => write!(f, "line {line}, column {column}: ")?,
+            (Some(file), None) => write!(f, "{file}: ")?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.message)?;
+        if let (true, Some(source), Some((line, column))) =
+            (f.alternate(), &self.source_line, self.position)
+        {
+            let gutter = " ".repeat(line.to_string().len());
+            write!(
+                f,
+                "\n{gutter} |\n{line} | {source}\n{gutter} | {}^",
+                " ".repeat(column - 1)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Error {
+        Error::new(err.to_string())
+    }
+}
+
+/// Parses `field`, a slice of `input`, pointing at it on failure.
+pub fn parse_at<T>(input: &str, field: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field
+        .parse()
+        .map_err(|err| Error::new(format!("cannot parse {field:?}: {err}")).at(input, field))
+}
+
+/// Shorthand for an error at `fragment` of `input`.
+pub fn fail_at<T>(input: &str, fragment: &str, message: impl Into<String>) -> Result<T> {
+    Err(Error::new(message).at(input, fragment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_fragments() {
+        let input = "1 2\n3 x4\n";
+        let field = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        let err = parse_at::<u32>(input, field)
+            .unwrap_err()
+            .in_file(Path::new("06.input"));
+        assert_eq!(err.position, Some((2, 3)));
+        assert_eq!(
+            err.to_string(),
+            "06.input:2:3: cannot parse \"x4\": invalid digit found in string"
+        );
+        assert_eq!(
+            format!("{err:#}").lines().skip(1).collect::<Vec<_>>(),
+            ["  |", "2 | 3 x4", "  |   ^"]
+        );
+    }
+
+    #[test]
+    fn relocates_errors_in_parts() {
+        let input = "a: 1\nb: 2\nc: x\n";
+        let part = &input[5..];
+        let field = &part[8..9];
+        let err = parse_at::<u32>(part, field).unwrap_err();
+        assert_eq!(err.position, Some((2, 4)));
+        assert_eq!(err.within(input, part).position, Some((3, 4)));
+
+        let err = parse_at::<u32>(field, field).unwrap_err();
+        assert_eq!(err.within(input, field).position, Some((3, 4)));
+    }
+
+    #[test]
+    fn foreign_fragments_are_not_located() {
+        let other = String::from("x");
+        let err = Error::new("bad").at("abc", &other);
+        assert_eq!(err.position, None);
+        assert_eq!(err.to_string(), "bad");
+    }
+}
@@ -0,0 +1,261 @@
+//! Downloading puzzle inputs into the `input/{YEAR}/{DAY}.input` tree.
+//!
+//! The session token is the `session` cookie of a logged in browser, read
+//! from the `AOC_SESSION` environment variable or else from the `session`
+//! file in the config directory (`$XDG_CONFIG_HOME/aoc` or `~/.config/aoc`).
+//! Inputs never change, so one already on disk is never downloaded again,
+//! and requests are spaced out by at least [`DEFAULT_INTERVAL`], across runs
+//! too, as the site asks.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = concat!("advent-of-code-rust/", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession(Option<PathBuf>),
+    Status { url: String, status: u16 },
+    Request { url: String, message: String },
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession(Some(path)) => write!(
+                f,
+                "no session token: set {SESSION_ENV} or write it to {}",
+                path.display()
+            ),
+            FetchError::NoSession(None) => write!(f, "no session token: set {SESSION_ENV}"),
+            FetchError::Status { url, status } => {
+                write!(f, "{url} answered {status}")?;
+                match status {
+                    400 | 401 | 403 => write!(f, " (has the session token expired?)"),
+                    404 => write!(f, " (is the puzzle unlocked yet?)"),
+                    _ => Ok(()),
+                }
+            }
+            FetchError::Request { url, message } => write!(f, "{url}: {message}"),
+            FetchError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Where the session token and the time of the last request are kept.
+pub fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// The session token from the environment or the config directory.
+pub fn session() -> Result<String, FetchError> {
+    let file = config_dir().map(|dir| dir.join("session"));
+    env::var(SESSION_ENV)
+        .ok()
+        .or_else(|| file.as_ref().and_then(|f| fs::read_to_string(f).ok()))
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .ok_or(FetchError::NoSession(file))
+}
+
+/// Whether an input is on disk already. The empty file `new` leaves does not
+/// count.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Whether an input was downloaded or found on disk.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    interval: Duration,
+    /// Touched on every request, to space them out across runs.
+    stamp: Option<PathBuf>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let config = ureq::Agent::config_builder()
+            .timeout_global(Some(TIMEOUT))
+            .user_agent(USER_AGENT)
+            .build();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            interval: DEFAULT_INTERVAL,
+            stamp: None,
+            agent: config.into(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Client {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_stamp(mut self, stamp: impl Into<PathBuf>) -> Client {
+        self.stamp = Some(stamp.into());
+        self
+    }
+
+    pub fn input_url(&self, year: &str, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+
+    /// Downloads a day's input to `path`, unless it is already there.
+    pub fn fetch(&self, year: &str, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+        if is_cached(path) {
+            return Ok(Fetched::Cached(path.to_path_buf()));
+        }
+        let text = self.get(&self.input_url(year, day))?;
+        let io_error = |err| FetchError::Io(path.to_path_buf(), err);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        // Written aside first so that a failed write leaves no partial input.
+        let partial = path.with_extension("input.partial");
+        fs::write(&partial, text).map_err(io_error)?;
+        fs::rename(&partial, path).map_err(io_error)?;
+        Ok(Fetched::Downloaded(path.to_path_buf()))
+    }
+
+    fn get(&self, url: &str) -> Result<String, FetchError> {
+        self.wait();
+        let request_error = |err: ureq::Error| match err {
+            ureq::Error::StatusCode(status) => FetchError::Status {
+                url: url.to_string(),
+                status,
+            },
+            err => FetchError::Request {
+                url: url.to_string(),
+                message: err.to_string(),
+            },
+        };
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .map_err(request_error)?;
+        response.body_mut().read_to_string().map_err(request_error)
+    }
+
+    /// Sleeps until `interval` has passed since the last request, then marks
+    /// the time of this one.
+    fn wait(&self) {
+        let Some(stamp) = &self.stamp else {
+            return;
+        };
+        let last = fs::metadata(stamp).and_then(|m| m.modified()).ok();
+        if let Some(since) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            thread::sleep(self.interval.saturating_sub(since));
+        }
+        if let Some(dir) = stamp.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(stamp, "");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Answers requests for day 6 with an input and any other with a 404,
+    /// sending each request line and cookie back.
+    fn stub_server() -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if line.to_ascii_lowercase().starts_with("cookie:") {
+                        cookie = line["cookie:".len()..].trim().to_string();
+                    }
+                }
+                let response = if request.starts_with("GET /2024/day/6/input ") {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 9\r\nConnection: close\r\n\r\n..#.\n#..\n"
+                } else {
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+                let _ = sender.send((request.trim().to_string(), cookie));
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn downloads_once_and_reports_errors() {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let (url, requests) = stub_server();
+        let client = Client::new(&format!("{url}/"), "secret")
+            .with_interval(Duration::ZERO)
+            .with_stamp(dir.join("last-request"));
+        let path = dir.join("2024").join("06.input");
+
+        assert_eq!(
+            client.fetch("2024", 6, &path).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "..#.\n#..\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            (
+                "GET /2024/day/6/input HTTP/1.1".to_string(),
+                "session=secret".to_string()
+            )
+        );
+        assert!(dir.join("last-request").is_file());
+
+        assert_eq!(
+            client.fetch("2024", 6, &path).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        let missing = dir.join("2024").join("07.input");
+        let err = client.fetch("2024", 7, &missing).unwrap_err();
+        assert!(matches!(err, FetchError::Status { status: 404, .. }));
+        assert!(!missing.exists());
+        // Only the requests for days 6 and 7 reached the server.
+        assert_eq!(requests.recv().unwrap().0, "GET /2024/day/7/input HTTP/1.1");
+        assert!(requests.try_recv().is_err());
+    }
+}
@@ -0,0 +1,373 @@
+//! Benchmark history, appended to by `bench --save` and read by `compare`.
+//!
+//! Each non-comment line of the history file holds
+//! `commit year day stage iterations min_ns median_ns mean_ns stddev_ns`.
+
+use std::env;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::bench::{Measurement, Stage, Stats};
+
+pub const FILE_NAME: &str = "bench-history.txt";
+pub const UNKNOWN_COMMIT: &str = "unknown";
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(PathBuf, io::Error),
+    Syntax { path: PathBuf, line: usize },
+    UnknownCommit(String),
+    NoBaseline(String),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            HistoryError::Syntax { path, line } => write!(
+                f,
+                "{}:{line}: expected `commit year day stage iterations min median mean stddev`",
+                path.display()
+            ),
+            HistoryError::UnknownCommit(commit) => {
+                write!(f, "no benchmark history for commit {commit}")
+            }
+            HistoryError::NoBaseline(commit) => {
+                write!(
+                    f,
+                    "no earlier commit in the history to compare {commit} with"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub commit: String,
+    pub measurement: Measurement,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = &self.measurement;
+        write!(
+            f,
+            "{} {} {:02} {} {} {} {} {} {}",
+            self.commit,
+            m.year,
+            m.day,
+            m.stage,
+            m.stats.iterations,
+            m.stats.min.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.mean.as_nanos(),
+            m.stats.stddev.as_nanos(),
+        )
+    }
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [
+        commit,
+        year,
+        day,
+        stage,
+        iterations,
+        min,
+        median,
+        mean,
+        stddev,
+    ] = fields[..]
+    else {
+        return None;
+    };
+    let nanos = |s: &str| s.parse::<u64>().ok().map(Duration::from_nanos);
+    Some(Record {
+        commit: commit.to_string(),
+        measurement: Measurement {
+            year: year.to_string(),
+            day: day.parse().ok()?,
+            stage: stage.parse().ok()?,
+            stats: Stats {
+                iterations: iterations.parse().ok()?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+                stddev: nanos(stddev)?,
+            },
+            allocations: None,
+        },
+    })
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Reads the history at `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<History, HistoryError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(err) => return Err(HistoryError::Io(path.to_path_buf(), err)),
+        };
+        let mut records = Vec::new();
+        for (k, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            records.push(parse_record(line).ok_or_else(|| HistoryError::Syntax {
+                path: path.to_path_buf(),
+                line: k + 1,
+            })?);
+        }
+        Ok(History { records })
+    }
+
+    pub fn append(path: &Path, records: &[Record]) -> Result<(), HistoryError> {
+        let io_error = |err| HistoryError::Io(path.to_path_buf(), err);
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        if is_new {
+            writeln!(
+                file,
+                "# commit year day stage iterations min_ns median_ns mean_ns stddev_ns"
+            )
+            .map_err(io_error)?;
+        }
+        for record in records {
+            writeln!(file, "{record}").map_err(io_error)?;
+        }
+        Ok(())
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Recorded commits, in the order they were first benchmarked.
+    pub fn commits(&self) -> Vec<&str> {
+        let mut commits: Vec<&str> = Vec::new();
+        for record in &self.records {
+            if !commits.contains(&record.commit.as_str()) {
+                commits.push(&record.commit);
+            }
+        }
+        commits
+    }
+
+    /// Finds the recorded commit starting with `prefix`.
+    pub fn resolve(&self, prefix: &str) -> Result<&str, HistoryError> {
+        let matches: Vec<&str> = self
+            .commits()
+            .into_iter()
+            .filter(|c| c.starts_with(prefix))
+            .collect();
+        match matches[..] {
+            [commit] => Ok(commit),
+            _ => Err(HistoryError::UnknownCommit(prefix.to_string())),
+        }
+    }
+
+    /// The commit benchmarked before `commit`.
+    pub fn previous(&self, commit: &str) -> Result<&str, HistoryError> {
+        let commits = self.commits();
+        match commits.iter().position(|c| *c == commit) {
+            Some(k) if k > 0 => Ok(commits[k - 1]),
+            _ => Err(HistoryError::NoBaseline(commit.to_string())),
+        }
+    }
+
+    /// Latest measurement of each year, day and stage for `commit`.
+    pub fn latest(&self, commit: &str) -> Vec<&Measurement> {
+        let mut latest: Vec<&Measurement> = Vec::new();
+        for record in self.records.iter().filter(|r| r.commit == commit) {
+            let m = &record.measurement;
+            match latest
+                .iter_mut()
+                .find(|l| l.year == m.year && l.day == m.day && l.stage == m.stage)
+            {
+                Some(l) => *l = m,
+                None => latest.push(m),
+            }
+        }
+        latest.sort_by_key(|m| (&m.year, m.day, m.stage));
+        latest
+    }
+
+    /// Median timings of every year, day and stage measured at both commits.
+    pub fn compare(&self, baseline: &str, current: &str) -> Vec<Comparison> {
+        let before = self.latest(baseline);
+        self.latest(current)
+            .into_iter()
+            .filter_map(|m| {
+                let b = before
+                    .iter()
+                    .find(|b| b.year == m.year && b.day == m.day && b.stage == m.stage)?;
+                Some(Comparison {
+                    year: m.year.clone(),
+                    day: m.day,
+                    stage: m.stage,
+                    baseline: b.stats.median,
+                    current: m.stats.median,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub year: String,
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Change of the median relative to the baseline, in percent.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() / baseline - 1.0) * 100.0
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+fn find_git_dir() -> Option<PathBuf> {
+    let starts = [
+        env::current_dir().ok(),
+        Some(env!("CARGO_MANIFEST_DIR").into()),
+    ];
+    starts.into_iter().flatten().find_map(|start| {
+        start
+            .ancestors()
+            .map(|dir| dir.join(".git"))
+            .find(|git| git.exists())
+    })
+}
+
+/// The directory holding `.git`, where the history file lives by default.
+pub fn repo_root() -> Option<PathBuf> {
+    find_git_dir().and_then(|git| git.parent().map(Path::to_path_buf))
+}
+
+pub fn default_path() -> PathBuf {
+    repo_root()
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".."))
+        .join(FILE_NAME)
+}
+
+/// Resolves `HEAD` by reading `.git` directly, following one level of
+/// `gitdir:` indirection for worktrees and submodules.
+pub fn current_commit() -> Option<String> {
+    let mut git_dir = find_git_dir()?;
+    if git_dir.is_file() {
+        let text = fs::read_to_string(&git_dir).ok()?;
+        let target = text.trim().strip_prefix("gitdir:")?.trim();
+        git_dir = git_dir.parent()?.join(target);
+    }
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let Some(reference) = head.trim().strip_prefix("ref:") else {
+        return Some(head.trim().to_string());
+    };
+    let reference = reference.trim();
+    if let Ok(hash) = fs::read_to_string(git_dir.join(reference)) {
+        return Some(hash.trim().to_string());
+    }
+    // Refs may also be packed, in which case the commondir of a worktree
+    // holds them.
+    let common = fs::read_to_string(git_dir.join("commondir"))
+        .map(|dir| git_dir.join(dir.trim()))
+        .unwrap_or(git_dir);
+    if let Ok(hash) = fs::read_to_string(common.join(reference)) {
+        return Some(hash.trim().to_string());
+    }
+    let packed = fs::read_to_string(common.join("packed-refs")).ok()?;
+    packed.lines().find_map(|line| {
+        let (hash, name) = line.split_once(' ')?;
+        (name == reference).then(|| hash.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u8, stage: Stage, median_ms: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+        Record {
+            commit: commit.to_string(),
+            measurement: Measurement {
+                year: "2024".to_string(),
+                day,
+                stage,
+                stats: Stats {
+                    iterations: 10,
+                    min: median,
+                    median,
+                    mean: median,
+                    stddev: Duration::ZERO,
+                },
+                allocations: None,
+            },
+        }
+    }
+
+    #[test]
+    fn record_round_trip() {
+        let original = record("abc123", 6, Stage::Part(2), 5);
+        assert_eq!(
+            original.to_string(),
+            "abc123 2024 06 part2 10 5000000 5000000 5000000 0"
+        );
+        assert_eq!(parse_record(&original.to_string()), Some(original));
+    }
+
+    #[test]
+    fn compare_against_previous_commit() {
+        let history = History {
+            records: vec![
+                record("aaa", 6, Stage::Part(2), 100),
+                record("aaa", 24, Stage::Part(2), 100),
+                record("bbb", 6, Stage::Part(2), 105),
+                record("bbb", 24, Stage::Part(2), 200),
+                record("bbb", 24, Stage::Part(2), 150),
+            ],
+        };
+        let current = history.resolve("b").unwrap();
+        let baseline = history.previous(current).unwrap();
+        assert_eq!(baseline, "aaa");
+
+        let comparisons = history.compare(baseline, current);
+        let regressed: Vec<u8> = comparisons
+            .iter()
+            .filter(|c| c.regressed(DEFAULT_THRESHOLD))
+            .map(|c| c.day)
+            .collect();
+        assert_eq!(regressed, vec![24]);
+        assert!(history.previous("aaa").is_err());
+        assert!(history.resolve("c").is_err());
+    }
+}
@@ -0,0 +1,201 @@
+//! Locating and reading puzzle inputs from the `input/{YEAR}/{DAY}.input` tree.
+//!
+//! The input directory is resolved in this order: a path set with [`set_root`]
+//! (e.g. from the `--input-dir` flag), the `AOC_INPUT_DIR` environment
+//! variable, the nearest `input` directory above the current working
+//! directory, and finally the `input` directory at the repository root.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const ENV_VAR: &str = "AOC_INPUT_DIR";
+pub const FLAG: &str = "--input-dir";
+/// The input source standing for stdin.
+pub const STDIN: &str = "-";
+
+static ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(
+                f,
+                "input file not found: {} (set {ENV_VAR} or pass {FLAG} to change the input directory)",
+                path.display()
+            ),
+            InputError::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound(_) => None,
+            InputError::Io(_, err) => Some(err),
+        }
+    }
+}
+
+/// Overrides the input directory. Only the first call has an effect.
+pub fn set_root(path: impl Into<PathBuf>) {
+    let _ = ROOT.set(path.into());
+}
+
+/// Applies `--input-dir <PATH>` (or `--input-dir=<PATH>`) from the process arguments.
+pub fn init_from_args() {
+    if let Some(path) = root_from_args(env::args()) {
+        set_root(path);
+    }
+}
+
+pub fn root_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == FLAG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.strip_prefix(FLAG).and_then(|a| a.strip_prefix('=')) {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
+
+/// The first argument that is neither [`FLAG`] nor its value, e.g. the input
+/// file passed to a day's binary.
+pub fn source_from_args(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == FLAG {
+            args.next();
+        } else if !arg.starts_with(&format!("{FLAG}=")) {
+            return Some(arg);
+        }
+    }
+    None
+}
+
+pub fn root() -> PathBuf {
+    if let Some(path) = ROOT.get() {
+        return path.clone();
+    }
+    if let Some(path) = env::var_os(ENV_VAR) {
+        return PathBuf::from(path);
+    }
+    if let Some(path) = env::current_dir().ok().as_deref().and_then(find_input_dir) {
+        return path;
+    }
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    find_input_dir(manifest_dir).unwrap_or_else(|| manifest_dir.join("../input"))
+}
+
+fn find_input_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join("input"))
+        .find(|dir| dir.is_dir())
+}
+
+/// Path of a day's input, e.g. `input/2024/06.input` or, for the
+/// `example2` variant, `input/2024/06.input.example2`.
+pub fn path(year: &str, day: &str, variant: Option<&str>) -> PathBuf {
+    path_in(&root(), year, day, variant)
+}
+
+pub fn path_in(root: &Path, year: &str, day: &str, variant: Option<&str>) -> PathBuf {
+    let file_name = match variant {
+        None => format!("{day}.input"),
+        Some(v) => format!("{day}.input.{v}"),
+    };
+    root.join(year).join(file_name)
+}
+
+pub fn read(year: &str, day: &str, variant: Option<&str>) -> Result<String, InputError> {
+    read_path(&path(year, day, variant))
+}
+
+/// Reads a file, or stdin for [`STDIN`], along with the path to name it by in
+/// error messages.
+pub fn read_source(source: &str) -> Result<(String, PathBuf), InputError> {
+    if source == STDIN {
+        let path = PathBuf::from("<stdin>");
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|err| InputError::Io(path.clone(), err))?;
+        return Ok((text, path));
+    }
+    let path = PathBuf::from(source);
+    Ok((read_path(&path)?, path))
+}
+
+pub fn read_path(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Io(path.to_path_buf(), err),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_paths() {
+        let root = Path::new("input");
+        assert_eq!(
+            path_in(root, "2024", "06", None),
+            Path::new("input/2024/06.input")
+        );
+        assert_eq!(
+            path_in(root, "2024", "06", Some("example2")),
+            Path::new("input/2024/06.input.example2")
+        );
+    }
+
+    #[test]
+    fn flag_from_args() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            root_from_args(args(&["06", "--input-dir", "/tmp/in"])),
+            Some(PathBuf::from("/tmp/in"))
+        );
+        assert_eq!(
+            root_from_args(args(&["--input-dir=/tmp/in"])),
+            Some(PathBuf::from("/tmp/in"))
+        );
+        assert_eq!(root_from_args(args(&["06"])), None);
+    }
+
+    #[test]
+    fn source_from_args_skips_the_flag() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            source_from_args(args(&["--input-dir", "/tmp/in", "-"])),
+            Some(STDIN.to_string())
+        );
+        assert_eq!(
+            source_from_args(args(&["--input-dir=/tmp/in", "06.txt"])),
+            Some("06.txt".to_string())
+        );
+        assert_eq!(source_from_args(args(&["--input-dir", "/tmp/in"])), None);
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let err = read_path(Path::new("does/not/exist.input")).unwrap_err();
+        assert!(matches!(err, InputError::NotFound(_)));
+    }
+}
@@ -0,0 +1,17 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod differential;
+pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod history;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod pool;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod watch;
@@ -0,0 +1,178 @@
+//! Levelled diagnostics for solvers, written to stderr so that answers on
+//! stdout stay clean.
+//!
+//! Events are logged with [`info!`](crate::info), [`debug!`](crate::debug)
+//! and [`trace!`](crate::trace), each taking a message and optional
+//! `key = value` fields:
+//!
+//! ```ignore
+//! aoc_common::debug!("best routes", cost, tiles = visited.len());
+//! ```
+//!
+//! A disabled event costs one relaxed atomic load; neither the message nor
+//! the fields are evaluated. The level is off by default and set by the
+//! runner's `-v` flags or the `AOC_LOG` environment variable.
+
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// The level for `count` repetitions of `-v`.
+    pub fn from_verbosity(count: usize) -> Level {
+        match count {
+            0 => Level::Off,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    /// Parses `off`, `info`, `debug` and `trace`, or a verbosity count.
+    pub fn parse(value: &str) -> Option<Level> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "off" | "" => Some(Level::Off),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            count => count.parse().ok().map(Level::from_verbosity),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Applies `AOC_LOG`, if set to a valid level.
+pub fn init_from_env() {
+    if let Some(level) = env::var(ENV_VAR).ok().as_deref().and_then(Level::parse) {
+        set_level(level);
+    }
+}
+
+#[inline]
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Writes an event; called by the logging macros once `enabled` has passed.
+pub fn emit(
+    level: Level,
+    target: &str,
+    message: fmt::Arguments,
+    fields: &[(&str, &dyn fmt::Debug)],
+) {
+    eprintln!("{}", format_event(level, target, message, fields));
+}
+
+fn format_event(
+    level: Level,
+    target: &str,
+    message: fmt::Arguments,
+    fields: &[(&str, &dyn fmt::Debug)],
+) -> String {
+    let mut line = format!("[{} {target}] {message}", level.name());
+    for (key, value) in fields {
+        line.push_str(&format!(" {key}={value:?}"));
+    }
+    line
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! log_event {
+    ($level:expr, $message:literal $(, $key:ident $(= $value:expr)?)* $(,)?) => {
+        if $crate::log::enabled($level) {
+            $crate::log::emit(
+                $level,
+                module_path!(),
+                format_args!($message),
+                &[$((stringify!($key), &$crate::log_event!(@value $key $(= $value)?) as &dyn ::std::fmt::Debug)),*],
+            );
+        }
+    };
+    (@value $key:ident = $value:expr) => { $value };
+    (@value $key:ident) => { $key };
+}
+
+/// Logs an event shown from `-v` on.
+#[macro_export]
+macro_rules! info {
+    ($($event:tt)+) => { $crate::log_event!($crate::log::Level::Info, $($event)+) };
+}
+
+/// Logs an event shown from `-vv` on.
+#[macro_export]
+macro_rules! debug {
+    ($($event:tt)+) => { $crate::log_event!($crate::log::Level::Debug, $($event)+) };
+}
+
+/// Logs an event shown with `-vvv`, for the hot paths.
+#[macro_export]
+macro_rules! trace {
+    ($($event:tt)+) => { $crate::log_event!($crate::log::Level::Trace, $($event)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        assert_eq!(Level::parse("debug"), Some(Level::Debug));
+        assert_eq!(Level::parse("3"), Some(Level::Trace));
+        assert_eq!(Level::parse("9"), Some(Level::Trace));
+        assert_eq!(Level::parse("loud"), None);
+        assert_eq!(Level::from_verbosity(1), Level::Info);
+    }
+
+    #[test]
+    fn event_format() {
+        let cost = 7036;
+        let line = format_event(
+            Level::Debug,
+            "aoc2024::day16",
+            format_args!("best routes"),
+            &[("cost", &cost), ("end", &(13, 1))],
+        );
+        assert_eq!(
+            line,
+            "[debug aoc2024::day16] best routes cost=7036 end=(13, 1)"
+        );
+    }
+
+    #[test]
+    fn disabled_events_are_not_evaluated() {
+        // No test raises the level, which is global to the test binary.
+        let mut evaluated = false;
+        let mut field = || {
+            evaluated = true;
+            0
+        };
+        crate::trace!("never shown", value = field());
+        assert!(!evaluated);
+        assert!(!enabled(Level::Off));
+    }
+}
@@ -0,0 +1,159 @@
+//! A scoped thread pool for running independent days and parts side by side.
+
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The number of jobs to run when not told otherwise: one per core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Wall time of a batch of jobs against the CPU time of the jobs themselves,
+/// summed over every thread.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timing {
+    pub wall: Duration,
+    /// `None` where the CPU time of a thread cannot be read.
+    pub cpu: Option<Duration>,
+    pub threads: usize,
+}
+
+impl Timing {
+    pub fn add(&mut self, other: Timing) {
+        self.wall += other.wall;
+        self.cpu = self.cpu.zip(other.cpu).map(|(a, b)| a + b);
+        self.threads = self.threads.max(other.threads);
+    }
+}
+
+/// The CPU time used so far by the calling thread.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn thread_cpu_time() -> Option<Duration> {
+    use std::ffi::{c_int, c_long};
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: c_long,
+        tv_nsec: c_long,
+    }
+
+    unsafe extern "C" {
+        fn clock_gettime(clock: c_int, time: *mut Timespec) -> c_int;
+    }
+
+    #[cfg(target_os = "linux")]
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+    #[cfg(target_os = "macos")]
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 16;
+
+    let mut time = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid timespec for the call to write to.
+    if unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut time) } != 0 {
+        return None;
+    }
+    Some(Duration::new(
+        u64::try_from(time.tv_sec).ok()?,
+        u32::try_from(time.tv_nsec).ok()?,
+    ))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// Applies `f` to every item on up to `jobs` threads, handing out items in
+/// order as threads free up, and returns the results in the order of `items`.
+pub fn map<T, R, F>(jobs: usize, items: &[T], f: F) -> (Vec<R>, Timing)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let cpu = Mutex::new(Some(Duration::ZERO));
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+    let worker = || {
+        let mut spent = Some(Duration::ZERO);
+        loop {
+            let k = next.fetch_add(1, Ordering::Relaxed);
+            let Some(item) = items.get(k) else {
+                break;
+            };
+            let started = thread_cpu_time();
+            let result = f(item);
+            let used = thread_cpu_time()
+                .zip(started)
+                .map(|(end, start)| end - start);
+            spent = spent.zip(used).map(|(a, b)| a + b);
+            results.lock().unwrap()[k] = Some(result);
+        }
+        let mut cpu = cpu.lock().unwrap();
+        *cpu = cpu.zip(spent).map(|(a, b)| a + b);
+    };
+
+    let threads = jobs.clamp(1, items.len().max(1));
+    if threads == 1 {
+        worker();
+    } else {
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(worker);
+            }
+        });
+    }
+
+    let results = results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is processed"))
+        .collect();
+    let timing = Timing {
+        wall: start.elapsed(),
+        cpu: cpu.into_inner().unwrap(),
+        threads,
+    };
+    (results, timing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_the_order_of_the_items() {
+        let items = (0..100_u64).collect::<Vec<_>>();
+        for jobs in [1, 4, 200] {
+            let (results, _) = map(jobs, &items, |&n| {
+                thread::sleep(Duration::from_micros(100 - n));
+                n * n
+            });
+            assert_eq!(results, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        }
+        assert!(map(4, &[] as &[u8], |&n| n).0.is_empty());
+    }
+
+    #[test]
+    fn cpu_time_is_summed_over_threads() {
+        let spin = |_: &()| {
+            let start = thread_cpu_time().unwrap();
+            while thread_cpu_time().unwrap() - start < Duration::from_millis(20) {}
+        };
+        let (_, timing) = map(4, &[(); 4], spin);
+        assert!(timing.cpu.unwrap() >= Duration::from_millis(80));
+
+        // Sleeping takes wall time but next to no CPU time.
+        let (_, timing) = map(4, &[(); 4], |_| thread::sleep(Duration::from_millis(20)));
+        assert!(timing.cpu.unwrap() < Duration::from_millis(20));
+        assert!(timing.wall >= Duration::from_millis(20));
+    }
+}
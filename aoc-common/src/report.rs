@@ -0,0 +1,258 @@
+//! The progress table of a year, as Markdown for the README or as a
+//! standalone HTML page.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::bench::format_duration;
+
+pub const MASK: &str = "hidden";
+
+/// A part's answer, `None` when solving it failed, and whether it matches the
+/// stored one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Part {
+    pub answer: Option<String>,
+    pub passed: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub title: String,
+    pub parts: Vec<Part>,
+    pub parse: Option<Duration>,
+    /// The parts' median times added up.
+    pub solve: Option<Duration>,
+    pub lines: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub year: String,
+    pub rows: Vec<Row>,
+    /// Leaves the answers out, keeping only whether they pass.
+    pub mask: bool,
+}
+
+/// Lines of a day's module that are neither blank nor comments, up to its
+/// tests.
+pub fn lines_of_code(source: &str) -> usize {
+    source
+        .lines()
+        .map(str::trim)
+        .take_while(|line| *line != "#[cfg(test)]")
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .count()
+}
+
+const HEADERS: [&str; 7] = [
+    "Day", "Puzzle", "Part 1", "Part 2", "Parse", "Solve", "Lines",
+];
+const LEGEND: &str = "✓ matches the stored answer, ✗ does not, ? has no stored answer";
+
+impl Report {
+    fn url(&self, day: u8) -> String {
+        format!("https://adventofcode.com/{}/day/{day}", self.year)
+    }
+
+    fn title(row: &Row) -> String {
+        match row.title.as_str() {
+            "" => format!("Day {}", row.day),
+            title => title.to_string(),
+        }
+    }
+
+    /// The cells after the title, as plain text.
+    fn cells(&self, row: &Row) -> Vec<String> {
+        let part = |k: usize| {
+            let Some(part) = row.parts.get(k) else {
+                return String::new();
+            };
+            let mark = match part.passed {
+                Some(true) => "✓",
+                Some(false) => "✗",
+                None => "?",
+            };
+            match (&part.answer, self.mask) {
+                (None, _) => format!("error {mark}"),
+                (Some(_), true) => format!("{MASK} {mark}"),
+                (Some(answer), false) => format!("{answer} {mark}"),
+            }
+        };
+        let time = |d: Option<Duration>| d.map_or("-".to_string(), format_duration);
+        vec![
+            part(0),
+            part(1),
+            time(row.parse),
+            time(row.solve),
+            row.lines.map_or("-".to_string(), |n| n.to_string()),
+        ]
+    }
+
+    fn summary(&self) -> String {
+        let parts = self.rows.iter().flat_map(|r| &r.parts);
+        let passed = parts.clone().filter(|p| p.passed == Some(true)).count();
+        let total = self
+            .rows
+            .iter()
+            .filter_map(|r| Some(r.parse? + r.solve?))
+            .sum::<Duration>();
+        format!(
+            "{passed} of {} parts pass, solved in {} altogether.",
+            parts.count(),
+            format_duration(total)
+        )
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let escape = |text: &str| text.replace('|', "\\|");
+        let mut out = format!("## Advent of Code {}\n\n", self.year);
+        writeln!(out, "| {} |", HEADERS.join(" | ")).unwrap();
+        writeln!(out, "|{}", "---|".repeat(HEADERS.len())).unwrap();
+        for row in &self.rows {
+            let cells = self.cells(row);
+            writeln!(
+                out,
+                "| {} | [{}]({}) | {} |",
+                row.day,
+                escape(&Self::title(row)),
+                self.url(row.day),
+                cells
+                    .iter()
+                    .map(|c| escape(c))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            )
+            .unwrap();
+        }
+        write!(out, "\n{}\n\n{LEGEND}\n", self.summary()).unwrap();
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let escape = |text: &str| {
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Advent of Code {year}</title>\n<style>\n\
+             body {{ font-family: sans-serif; margin: 2em; }}\n\
+             table {{ border-collapse: collapse; }}\n\
+             th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; }}\n\
+             td.number {{ text-align: right; font-family: monospace; }}\n\
+             </style>\n</head>\n<body>\n<h1>Advent of Code {year}</h1>\n<table>\n<tr>",
+            year = escape(&self.year)
+        );
+        for header in HEADERS {
+            write!(out, "<th>{header}</th>").unwrap();
+        }
+        out.push_str("</tr>\n");
+        for row in &self.rows {
+            write!(
+                out,
+                "<tr><td class=\"number\">{}</td><td><a href=\"{}\">{}</a></td>",
+                row.day,
+                self.url(row.day),
+                escape(&Self::title(row))
+            )
+            .unwrap();
+            for cell in self.cells(row) {
+                write!(out, "<td class=\"number\">{}</td>", escape(&cell)).unwrap();
+            }
+            out.push_str("</tr>\n");
+        }
+        write!(
+            out,
+            "</table>\n<p>{}</p>\n<p>{LEGEND}</p>\n</body>\n</html>\n",
+            escape(&self.summary())
+        )
+        .unwrap();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(mask: bool) -> Report {
+        Report {
+            year: "2024".to_string(),
+            rows: vec![
+                Row {
+                    day: 1,
+                    title: "Historian Hysteria".to_string(),
+                    parts: vec![
+                        Part {
+                            answer: Some("11".to_string()),
+                            passed: Some(true),
+                        },
+                        Part {
+                            answer: Some("30".to_string()),
+                            passed: Some(false),
+                        },
+                    ],
+                    parse: Some(Duration::from_micros(20)),
+                    solve: Some(Duration::from_micros(30)),
+                    lines: Some(42),
+                },
+                Row {
+                    day: 25,
+                    title: String::new(),
+                    parts: vec![Part {
+                        answer: None,
+                        passed: Some(false),
+                    }],
+                    parse: None,
+                    solve: None,
+                    lines: None,
+                },
+            ],
+            mask,
+        }
+    }
+
+    #[test]
+    fn markdown_table() {
+        let markdown = report(false).to_markdown();
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "## Advent of Code 2024");
+        assert_eq!(
+            lines[2],
+            "| Day | Puzzle | Part 1 | Part 2 | Parse | Solve | Lines |"
+        );
+        assert_eq!(
+            lines[4],
+            "| 1 | [Historian Hysteria](https://adventofcode.com/2024/day/1) \
+             | 11 ✓ | 30 ✗ | 20.00 µs | 30.00 µs | 42 |"
+        );
+        assert_eq!(
+            lines[5],
+            "| 25 | [Day 25](https://adventofcode.com/2024/day/25) | error ✗ |  | - | - | - |"
+        );
+        assert_eq!(
+            lines[7],
+            "1 of 3 parts pass, solved in 50.00 µs altogether."
+        );
+    }
+
+    #[test]
+    fn masked_html_page() {
+        let html = report(true).to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td class=\"number\">hidden ✓</td>"));
+        assert!(!html.contains(">11 "));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn lines_of_code_skip_comments_and_tests() {
+        let source =
+            "//! Docs\n\nuse std::fmt;\n\n// note\nfn f() {}\n\n#[cfg(test)]\nmod tests {}\n";
+        assert_eq!(lines_of_code(source), 2);
+    }
+}
@@ -0,0 +1,1826 @@
+//! The command line shared by the per-year binaries and the workspace
+//! dispatcher: listing days, running them and checking the results against
+//! the stored answers.
+
+use std::any::Any;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answers::{self, Answers, AnswersError};
+use crate::bench::{self, Measurement, Stage};
+use crate::differential::{self, Mismatch};
+use crate::error::Error;
+#[cfg(feature = "fetch")]
+use crate::fetch::{self, Client, Fetched};
+use crate::history::{self, Comparison, History, Record};
+use crate::input;
+use crate::json::Json;
+use crate::log::{self, Level};
+use crate::pool::{self, Timing};
+use crate::report::{self, Report, Row};
+use crate::scaffold;
+use crate::solution::{Answer, Day, Params, Parsed, Year};
+use crate::watch::{self, Snapshot};
+
+const USAGE: &str = "\
+Usage: {program} [--input-dir DIR] [--json] [-v|--verbose|-vv|-vvv] <command>
+
+Commands:
+  list                                List the registered days
+  run [<year>] <day> [--part N] [--input PATH|-] [--PARAM VALUE]...
+                                      Solve a day, reading the input from PATH or stdin
+  run <year>|--all [--part N] [--jobs N]
+                                      Solve every day of a year, or of every year
+  check [<year>] [<day>|--all] [--part N] [--examples] [--jobs N]
+                                      Compare the results with the stored answers
+  validate [<year>] [<day>|--all] [--input PATH|-] [--PARAM VALUE]...
+                                      Check inputs against the assumptions of their solvers
+  generate [<year>] <day> [--seed N] [--size N]
+                                      Print a random input for a day
+  difftest [<year>] [<day>|--all] [--seed N] [--count N] [--size N]
+                                      Compare solvers with their brute-force references
+  bench [<year>] [<day>|--all] [--part N] [--warmup N] [--iterations N] [--save]
+                                      Time parsing and solving separately
+  compare [<year>] [<day>|--all] [--baseline COMMIT] [--current COMMIT] [--threshold PCT]
+                                      Flag parts whose median time regressed
+  report [<year>] [--mask] [--html] [--output PATH] [--warmup N] [--iterations N]
+                                      Tabulate a year's answers, timings and lines of code
+  watch [<year>] <day> [--part N] [--interval MS]
+                                      Solve a day again whenever its source or inputs change
+  new --day D [--year Y]              Create a day from template_rs and register it
+  fetch --day D [--year Y] [--base-url URL] [--interval MS]
+                                      Download a day's puzzle input
+
+The year can be left out of a day when only one year is registered. Without a
+day, check, validate, difftest, bench and compare cover every day of the
+selected years. run and check also validate each input before solving it.
+generate picks the same input for the same seed, 0 by default; the size, 100 by
+default, counts lines, grid rows or whatever unit suits the day.
+
+difftest solves --count generated inputs, 100 by default, from --seed on and of
+size 6 unless told otherwise, with both the solver and the reference of each
+day that has one. The first input they disagree on is shrunk and printed.
+
+`bench --save` appends to the history file that `compare` reads, bench-history.txt
+at the repository root unless `--history PATH` is given. `compare` defaults to the
+checked out commit and the commit benchmarked before it, with a 10% threshold.
+Built with `--features count-allocations`, bench also reports the allocations,
+bytes allocated and peak live bytes of each stage's first run.
+
+report solves and benchmarks every day of a year and prints a Markdown table
+for the README, or a standalone HTML page with --html, to PATH if given. --mask
+leaves the answers out and only shows whether they match the stored ones.
+
+watch polls the day's module, its inputs and the answers file every --interval
+milliseconds, 500 by default. On a change it rebuilds the binary with cargo and
+solves the examples and then the real input, printing each answer, how long it
+took and whether it matches the stored answer.
+
+new creates the year's crate from template_year first if the workspace has
+none, adding it to the workspace members and to this dispatcher. If any step
+fails, whatever new wrote is undone.
+
+fetch writes the input to the input directory, unless it is already there, with
+the session cookie from AOC_SESSION or ~/.config/aoc/session. Requests go to
+--base-url, AOC_BASE_URL or https://adventofcode.com, at least --interval
+milliseconds apart, 5000 by default, even across runs. Only the workspace
+binary, built with the `fetch` feature, can download.
+
+run and check parse and solve the days on `--jobs` threads, one per core by
+default, and print the answers in day order.
+
+Day parameters override the puzzle's values, e.g. `run 14 --width 11 --height 7`.
+The input directory can also be set with the AOC_INPUT_DIR environment variable.
+
+-v (or --verbose), -vv and -vvv print the solvers' info, debug and trace events
+to stderr. The AOC_LOG environment variable sets the same levels by name, e.g.
+AOC_LOG=debug.";
+
+const DEFAULT_GENERATED_SIZE: usize = 100;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Help,
+    List,
+    Run,
+    Check,
+    Validate,
+    Generate,
+    Difftest,
+    Bench,
+    Compare,
+    Report,
+    Watch,
+    New,
+    Fetch,
+}
+
+#[derive(Default)]
+struct Options {
+    years: &'static [&'static Year],
+    json: bool,
+    days: Vec<&'static Day>,
+    part: Option<u8>,
+    input: Option<String>,
+    params: Params,
+    warmup: Option<usize>,
+    iterations: Option<usize>,
+    save: bool,
+    history: Option<PathBuf>,
+    baseline: Option<String>,
+    current: Option<String>,
+    threshold: Option<f64>,
+    examples: bool,
+    year: Option<String>,
+    day_number: Option<u8>,
+    jobs: Option<usize>,
+    seed: Option<u64>,
+    size: Option<usize>,
+    count: Option<u64>,
+    interval: Option<Duration>,
+    once: bool,
+    mask: bool,
+    html: bool,
+    output: Option<PathBuf>,
+    base_url: Option<String>,
+    log_level: Option<Level>,
+}
+
+struct PartResult {
+    year: &'static str,
+    day: u8,
+    part: u8,
+    variant: Option<String>,
+    params: Params,
+    answer: Result<Answer, Error>,
+    expected: Option<String>,
+    elapsed: Duration,
+}
+
+impl PartResult {
+    fn passed(&self) -> Option<bool> {
+        match (&self.answer, &self.expected) {
+            (Ok(answer), Some(expected)) => Some(answer.to_string() == *expected),
+            (Err(_), _) => Some(false),
+            (Ok(_), None) => None,
+        }
+    }
+
+    fn status(&self) -> String {
+        match (&self.answer, &self.expected, self.passed()) {
+            (Err(err), _, _) => format!("error: {err}"),
+            (Ok(answer), _, None) => format!("{answer} (no stored answer)"),
+            (Ok(answer), _, Some(true)) => format!("{answer} ok"),
+            (Ok(answer), Some(expected), Some(false)) => {
+                format!("{answer} FAIL (expected {expected})")
+            }
+            (Ok(answer), None, Some(false)) => format!("{answer} FAIL"),
+        }
+    }
+
+    fn label(&self) -> String {
+        let mut label = format!("{} day {:02} part {}", self.year, self.day, self.part);
+        if let Some(variant) = &self.variant {
+            label.push_str(&format!(" ({variant}"));
+            if !self.params.is_empty() {
+                label.push_str(&format!(" {}", self.params));
+            }
+            label.push(')');
+        }
+        label
+    }
+
+    fn to_json(&self) -> Json {
+        let (answer, error) = match &self.answer {
+            Ok(answer) => (Some(answer.to_string()), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+        Json::object([
+            ("year", self.year.into()),
+            ("day", self.day.into()),
+            ("part", self.part.into()),
+            ("variant", self.variant.clone().into()),
+            (
+                "params",
+                Json::object(self.params.iter().map(|(k, v)| (k, v.into()))),
+            ),
+            ("answer", answer.into()),
+            ("error", error.into()),
+            ("expected", self.expected.clone().into()),
+            ("passed", self.passed().into()),
+        ])
+    }
+}
+
+/// Runs the command line over `years`, which are expected in order.
+pub fn main(years: &'static [&'static Year]) -> ExitCode {
+    let mut args = env::args();
+    let program = args
+        .next()
+        .as_deref()
+        .map(Path::new)
+        .and_then(Path::file_stem)
+        .map_or("aoc".to_string(), |stem| {
+            stem.to_string_lossy().into_owned()
+        });
+    let usage = USAGE.replace("{program}", &program);
+    let args: Vec<String> = args.collect();
+    match parse_args(&args, years) {
+        Ok((Command::Help, _)) => {
+            println!("{usage}");
+            ExitCode::SUCCESS
+        }
+        Ok((command, options)) => {
+            log::init_from_env();
+            if let Some(level) = options.log_level {
+                log::set_level(level);
+            }
+            execute(command, options)
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{usage}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn is_year(value: &str) -> bool {
+    value.len() == 4 && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn parse_year(value: &str, years: &'static [&'static Year]) -> Result<&'static Year, String> {
+    years
+        .iter()
+        .copied()
+        .find(|y| y.year == value)
+        .ok_or_else(|| format!("unknown year: {value}"))
+}
+
+fn parse_day(value: &str, year: &'static Year) -> Result<&'static Day, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .and_then(|day| year.days.iter().find(|d| d.day == day))
+        .ok_or_else(|| format!("unknown day: {} {value}", year.year))
+}
+
+fn parse_args(
+    args: &[String],
+    years: &'static [&'static Year],
+) -> Result<(Command, Options), String> {
+    let mut options = Options {
+        years,
+        ..Options::default()
+    };
+    let mut command = None;
+    let mut all = false;
+    let mut year = None;
+    let mut day = None;
+    let mut raw_params = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("{flag} needs a value"))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok((Command::Help, options)),
+            "--json" => options.json = true,
+            "--verbose" => options.log_level = Some(Level::Info),
+            flag if flag.len() > 1
+                && flag.starts_with('-')
+                && flag[1..].bytes().all(|b| b == b'v') =>
+            {
+                options.log_level = Some(Level::from_verbosity(flag.len() - 1));
+            }
+            "--all" => all = true,
+            "--save" => options.save = true,
+            "--examples" => options.examples = true,
+            "--once" => options.once = true,
+            "--mask" => options.mask = true,
+            "--html" => options.html = true,
+            "--output" => options.output = Some(value(arg)?.into()),
+            "--base-url" => options.base_url = Some(value(arg)?),
+            "--year" => options.year = Some(value(arg)?),
+            "--day" => {
+                let raw = value(arg)?;
+                let day = raw
+                    .parse::<u8>()
+                    .map_err(|_| format!("{arg} needs a day number, got {raw}"))?;
+                options.day_number = Some(day);
+            }
+            "--history" => options.history = Some(value(arg)?.into()),
+            "--baseline" => options.baseline = Some(value(arg)?),
+            "--current" => options.current = Some(value(arg)?),
+            "--threshold" => {
+                let raw = value(arg)?;
+                let threshold = raw
+                    .trim_end_matches('%')
+                    .parse::<f64>()
+                    .map_err(|_| format!("{arg} needs a percentage, got {raw}"))?;
+                options.threshold = Some(threshold);
+            }
+            "--input-dir" => input::set_root(value(arg)?),
+            "--input" => options.input = Some(value(arg)?),
+            "--part" => {
+                let part = value(arg)?;
+                options.part = match part.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("invalid part: {part}")),
+                };
+            }
+            "--jobs" | "-j" => {
+                let raw = value(arg)?;
+                let jobs = raw
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&jobs| jobs > 0)
+                    .ok_or_else(|| format!("{arg} needs a positive count, got {raw}"))?;
+                options.jobs = Some(jobs);
+            }
+            "--seed" => {
+                let raw = value(arg)?;
+                let seed = raw
+                    .parse::<u64>()
+                    .map_err(|_| format!("{arg} needs a number, got {raw}"))?;
+                options.seed = Some(seed);
+            }
+            "--size" => {
+                let raw = value(arg)?;
+                let size = raw
+                    .parse::<usize>()
+                    .map_err(|_| format!("{arg} needs a count, got {raw}"))?;
+                options.size = Some(size);
+            }
+            "--count" => {
+                let raw = value(arg)?;
+                let count = raw
+                    .parse::<u64>()
+                    .map_err(|_| format!("{arg} needs a count, got {raw}"))?;
+                options.count = Some(count);
+            }
+            "--interval" => {
+                let raw = value(arg)?;
+                let millis = raw
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|&millis| millis > 0)
+                    .ok_or_else(|| {
+                        format!("{arg} needs a positive number of milliseconds, got {raw}")
+                    })?;
+                options.interval = Some(Duration::from_millis(millis));
+            }
+            "--warmup" | "--iterations" => {
+                let raw = value(arg)?;
+                let count = raw
+                    .parse::<usize>()
+                    .map_err(|_| format!("{arg} needs a count, got {raw}"))?;
+                if arg == "--warmup" {
+                    options.warmup = Some(count);
+                } else if count == 0 {
+                    return Err(format!("{arg} needs at least one iteration"));
+                } else {
+                    options.iterations = Some(count);
+                }
+            }
+            flag if flag.starts_with("--") && flag.len() > 2 => {
+                let name = flag[2..].replace('-', "_");
+                let raw = value(arg)?;
+                let parsed = raw
+                    .parse::<i64>()
+                    .map_err(|_| format!("{flag} needs an integer, got {raw}"))?;
+                raw_params.push((name, parsed));
+            }
+            positional if command.is_none() => {
+                command = Some(match positional {
+                    "list" => Command::List,
+                    "run" => Command::Run,
+                    "check" => Command::Check,
+                    "validate" => Command::Validate,
+                    "generate" => Command::Generate,
+                    "difftest" => Command::Difftest,
+                    "bench" => Command::Bench,
+                    "compare" => Command::Compare,
+                    "report" => Command::Report,
+                    "watch" => Command::Watch,
+                    "new" => Command::New,
+                    "fetch" => Command::Fetch,
+                    "help" => Command::Help,
+                    _ => return Err(format!("unknown command: {positional}")),
+                });
+            }
+            positional if is_year(positional) && year.is_none() && day.is_none() => {
+                year = Some(parse_year(positional, years)?);
+            }
+            positional if day.is_none() => day = Some(positional.to_string()),
+            positional => return Err(format!("unexpected argument: {positional}")),
+        }
+    }
+
+    let command = command.unwrap_or(Command::Help);
+    match command {
+        Command::Run
+        | Command::Check
+        | Command::Validate
+        | Command::Generate
+        | Command::Difftest
+        | Command::Bench
+        | Command::Compare
+        | Command::Report
+        | Command::Watch => {
+            if all && day.is_some() {
+                return Err("--all cannot be combined with a day".to_string());
+            }
+            if command == Command::Report {
+                if day.is_some() {
+                    return Err("report covers a whole year, not a day".to_string());
+                }
+                if year.is_none() && years.len() > 1 {
+                    return Err("report needs a year".to_string());
+                }
+            }
+            if let Some(day) = day {
+                let year = match (year, years) {
+                    (Some(year), _) => year,
+                    (None, [year]) => year,
+                    (None, _) => return Err(format!("missing year for day {day}")),
+                };
+                options.days.push(parse_day(&day, year)?);
+            } else if command == Command::Generate {
+                return Err("generate needs a day".to_string());
+            } else if command == Command::Watch {
+                return Err("watch needs a day".to_string());
+            } else if all || year.is_some() || command != Command::Run {
+                options.days = years
+                    .iter()
+                    .filter(|y| year.is_none_or(|year| y.year == year.year))
+                    .flat_map(|y| y.days)
+                    .collect();
+            } else {
+                return Err("missing day (or --all)".to_string());
+            }
+        }
+        Command::New => {
+            if options.day_number.is_none() {
+                return Err("new needs --day".to_string());
+            }
+            if options.year.as_deref().is_some_and(|year| !is_year(year)) {
+                return Err("--year needs a four digit year".to_string());
+            }
+        }
+        Command::Fetch => {
+            if let Some(day) = day {
+                return Err(format!("unexpected argument: {day}"));
+            }
+            if !options
+                .day_number
+                .is_some_and(|day| (1..=25).contains(&day))
+            {
+                return Err("fetch needs --day, from 1 to 25".to_string());
+            }
+            if options.year.as_deref().is_some_and(|year| !is_year(year)) {
+                return Err("--year needs a four digit year".to_string());
+            }
+        }
+        Command::Help | Command::List => {
+            if let Some(day) = day {
+                return Err(format!("unexpected argument: {day}"));
+            }
+        }
+    }
+    if options.input.is_some()
+        && (!matches!(command, Command::Run | Command::Validate | Command::Bench)
+            || options.days.len() != 1)
+    {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    for (name, value) in raw_params {
+        if !options
+            .days
+            .iter()
+            .any(|d| d.params.contains(&name.as_str()))
+        {
+            return Err(format!("unknown parameter: --{}", name.replace('_', "-")));
+        }
+        options.params.set(&name, value);
+    }
+
+    Ok((command, options))
+}
+
+fn execute(command: Command, options: Options) -> ExitCode {
+    match command {
+        Command::Help => unreachable!("help is printed by main"),
+        Command::List => {
+            list(&options);
+            ExitCode::SUCCESS
+        }
+        Command::Run => {
+            let (results, timing) = solve_jobs(&options, &input_jobs(&options, None));
+            report_run(&results, timing, options.json)
+        }
+        Command::Check => match load_answers(&options) {
+            Ok(answers) => {
+                let mut jobs = input_jobs(&options, Some(&answers));
+                if options.examples {
+                    jobs.extend(example_jobs(&options, &answers));
+                }
+                let (results, timing) = solve_jobs(&options, &jobs);
+                report_check(&results, timing, options.json)
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Validate => report_validation(&validate(&options), options.json),
+        Command::Generate => generate(&options),
+        Command::Difftest => report_difftest(&difftest(&options), options.json),
+        Command::Bench => bench(&options),
+        Command::Report => report(&options),
+        Command::Watch if options.once => watch_once(&options),
+        Command::Watch => watch(&options),
+        Command::New => new_day(&options),
+        Command::Fetch => fetch(&options),
+        Command::Compare => match compare(&options) {
+            Ok(code) => code,
+            Err(err) => {
+                eprintln!("error: {err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+fn list(options: &Options) {
+    let days = options.years.iter().flat_map(|y| y.days);
+    if options.json {
+        let days = days
+            .map(|day| {
+                Json::object([
+                    ("year", day.year.into()),
+                    ("day", day.day.into()),
+                    ("parts", day.parts.into()),
+                    ("params", day.params.to_vec().into()),
+                ])
+            })
+            .collect::<Vec<_>>();
+        println!("{}", Json::Array(days));
+        return;
+    }
+
+    for day in days {
+        let mut line = format!("{} day {}", day.year, day.name());
+        if day.parts == 1 {
+            line.push_str(" (part 1 only)");
+        }
+        for param in day.params {
+            line.push_str(&format!(" --{}", param.replace('_', "-")));
+        }
+        println!("{line}");
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "solver panicked".to_string(),
+        },
+    }
+}
+
+/// The input text along with the file it came from, for error messages.
+fn read_input(day: &Day, source: Option<&str>) -> Result<(String, PathBuf), Error> {
+    match source {
+        None => Ok((day.read_input(None)?, day.input_path(None))),
+        Some(source) => Ok(input::read_source(source)?),
+    }
+}
+
+/// Runs a parser or solver, turning a panic into an error and naming the
+/// input file in whatever error comes out.
+fn caught<T>(file: &Path, f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::new(panic_message(payload))))
+        .map_err(|err| err.in_file(file))
+}
+
+/// The answers of every year with a selected day.
+fn load_answers(options: &Options) -> Result<Vec<(&'static str, Answers)>, AnswersError> {
+    options
+        .years
+        .iter()
+        .filter(|y| options.days.iter().any(|d| d.year == y.year))
+        .map(|y| Ok((y.year, Answers::load(y)?)))
+        .collect()
+}
+
+fn answers_for<'a>(answers: &'a [(&str, Answers)], year: &str) -> Option<&'a Answers> {
+    answers.iter().find(|(y, _)| *y == year).map(|(_, a)| a)
+}
+
+/// An input to parse once, and the parts to solve with it.
+struct Job {
+    day: &'static Day,
+    variant: Option<String>,
+    parts: Vec<Task>,
+}
+
+struct Task {
+    part: u8,
+    params: Params,
+    expected: Option<String>,
+}
+
+impl Job {
+    /// The parsed input along with its text, which the day validates with each
+    /// part's parameters.
+    fn parse(&self, source: Option<&str>) -> Result<(Parsed, String, PathBuf), Error> {
+        let (text, file) = match self.variant.as_deref() {
+            None => read_input(self.day, source)?,
+            Some(variant) => (
+                self.day.read_input(Some(variant))?,
+                self.day.input_path(Some(variant)),
+            ),
+        };
+        let parsed = caught(&file, || self.day.parse(&text))?;
+        Ok((parsed, text, file))
+    }
+
+    /// Every assumption of the day that its input breaks, or the error of a
+    /// validation that panicked.
+    fn validate(&self, text: &str, file: &Path, params: &Params) -> Result<Vec<Error>, Error> {
+        let violations = caught(file, || Ok(self.day.validate(text, params)))?;
+        Ok(violations
+            .into_iter()
+            .map(|violation| violation.in_file(file))
+            .collect())
+    }
+}
+
+/// The real input of every selected day.
+fn input_jobs(options: &Options, answers: Option<&[(&str, Answers)]>) -> Vec<Job> {
+    options
+        .days
+        .iter()
+        .map(|day| Job {
+            day,
+            variant: None,
+            parts: (1..=day.parts)
+                .filter(|p| options.part.is_none_or(|part| part == *p))
+                .map(|part| Task {
+                    part,
+                    params: options.params.clone(),
+                    expected: answers
+                        .and_then(|a| answers_for(a, day.year))
+                        .and_then(|a| a.get(day.day, part, None))
+                        .map(str::to_string),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Every example in the answers file, with the parameters stored alongside
+/// its answer.
+fn example_jobs(options: &Options, answers: &[(&str, Answers)]) -> Vec<Job> {
+    let mut jobs = Vec::new();
+    for day in options.days.iter() {
+        let Some(answers) = answers_for(answers, day.year) else {
+            continue;
+        };
+        let entries = answers
+            .entries()
+            .iter()
+            .filter(|e| e.day == day.day && e.variant != answers::INPUT_VARIANT)
+            .filter(|e| options.part.is_none_or(|part| part == e.part))
+            .collect::<Vec<_>>();
+        let mut variants = entries.iter().map(|e| &e.variant).collect::<Vec<_>>();
+        variants.sort();
+        variants.dedup();
+        for variant in variants {
+            jobs.push(Job {
+                day,
+                variant: Some(variant.clone()),
+                parts: entries
+                    .iter()
+                    .filter(|e| e.variant == *variant)
+                    .map(|entry| Task {
+                        part: entry.part,
+                        params: entry.params.clone(),
+                        expected: Some(entry.answer.clone()),
+                    })
+                    .collect(),
+            });
+        }
+    }
+    jobs
+}
+
+/// Parses every job's input, then solves all their parts, each step spread
+/// over `options.jobs` threads. Results come back in the order of `jobs`.
+fn solve_jobs(options: &Options, jobs: &[Job]) -> (Vec<PartResult>, Timing) {
+    let threads = options.jobs.unwrap_or_else(pool::default_jobs);
+    let source = options.input.as_deref();
+    let (parsed, mut timing) = pool::map(threads, jobs, |job| job.parse(source));
+
+    let tasks = jobs
+        .iter()
+        .zip(&parsed)
+        .flat_map(|(job, parsed)| job.parts.iter().map(move |task| (job, parsed, task)))
+        .collect::<Vec<_>>();
+    let (results, solving) = pool::map(threads, &tasks, |&(job, parsed, task)| {
+        let start = Instant::now();
+        let answer = match parsed {
+            Err(err) => Err(err.clone()),
+            Ok((parsed, text, file)) => match job.validate(text, file, &task.params) {
+                Err(err) => Err(err),
+                Ok(violations) => match violations.into_iter().next() {
+                    Some(violation) => Err(violation),
+                    None => caught(file, || job.day.solve(parsed, task.part, &task.params)),
+                },
+            },
+        };
+        PartResult {
+            year: job.day.year,
+            day: job.day.day,
+            part: task.part,
+            variant: job.variant.clone(),
+            params: task.params.clone(),
+            answer,
+            expected: task.expected.clone(),
+            elapsed: start.elapsed(),
+        }
+    });
+    timing.add(solving);
+    (results, timing)
+}
+
+fn bench(options: &Options) -> ExitCode {
+    let warmup = options.warmup.unwrap_or(bench::DEFAULT_WARMUP);
+    let iterations = options.iterations.unwrap_or(bench::DEFAULT_ITERATIONS);
+    let mut measurements = Vec::new();
+    let mut failed = false;
+    for day in options.days.iter() {
+        let parts = (1..=day.parts)
+            .filter(|p| options.part.is_none_or(|part| part == *p))
+            .collect::<Vec<_>>();
+        let result = read_input(day, options.input.as_deref()).and_then(|(text, file)| {
+            caught(&file, || {
+                bench::bench_day(day, &text, &parts, &options.params, warmup, iterations)
+            })
+        });
+        match result {
+            Ok(day_measurements) => {
+                if !options.json {
+                    print_measurements(&day_measurements);
+                }
+                measurements.extend(day_measurements);
+            }
+            Err(err) => {
+                eprintln!("{} day {}: error: {err:#}", day.year, day.name());
+                failed = true;
+            }
+        }
+    }
+
+    if options.save {
+        let commit = history::current_commit().unwrap_or(history::UNKNOWN_COMMIT.to_string());
+        let records = measurements
+            .iter()
+            .map(|m| Record {
+                commit: commit.clone(),
+                measurement: m.clone(),
+            })
+            .collect::<Vec<_>>();
+        if let Err(err) = History::append(&history_path(options), &records) {
+            eprintln!("error: {err}");
+            failed = true;
+        }
+    }
+
+    if options.json {
+        println!(
+            "{}",
+            Json::Array(measurements.iter().map(Measurement::to_json).collect())
+        );
+    } else if options.days.len() > 1 {
+        println!();
+        print_bench_summary(&measurements);
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_measurements(measurements: &[Measurement]) {
+    for m in measurements {
+        println!(
+            "{} day {:02} {:<5}  min {:>10}  median {:>10}  mean {:>10}  stddev {:>10}  ({} runs)",
+            m.year,
+            m.day,
+            m.stage.to_string(),
+            bench::format_duration(m.stats.min),
+            bench::format_duration(m.stats.median),
+            bench::format_duration(m.stats.mean),
+            bench::format_duration(m.stats.stddev),
+            m.stats.iterations,
+        );
+        if let Some(allocations) = m.allocations {
+            println!(
+                "{:>17}  allocs {:>10}  bytes {:>11}  peak {:>12}",
+                "",
+                allocations.count,
+                bench::format_bytes(allocations.bytes),
+                bench::format_bytes(allocations.peak as u64),
+            );
+        }
+    }
+}
+
+fn print_bench_summary(measurements: &[Measurement]) {
+    println!(
+        "{:>4}  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Year", "Day", "parse", "part1", "part2", "total"
+    );
+    let mut days = measurements
+        .iter()
+        .map(|m| (m.year.as_str(), m.day))
+        .collect::<Vec<_>>();
+    days.dedup();
+    let mut grand_total = Duration::ZERO;
+    for (year, day) in days {
+        let median = |stage: Stage| {
+            measurements
+                .iter()
+                .find(|m| m.year == year && m.day == day && m.stage == stage)
+                .map(|m| m.stats.median)
+        };
+        let cell = |d: Option<Duration>| d.map_or("-".to_string(), bench::format_duration);
+        let stages = [Stage::Parse, Stage::Part(1), Stage::Part(2)].map(median);
+        let total = stages.iter().flatten().sum::<Duration>();
+        grand_total += total;
+        println!(
+            "{:>4}  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+            year,
+            format!("{day:02}"),
+            cell(stages[0]),
+            cell(stages[1]),
+            cell(stages[2]),
+            cell(Some(total)),
+        );
+    }
+    println!("{:>9}  {:>46}", "all", bench::format_duration(grand_total));
+}
+
+/// Solves and benchmarks every selected day for the report of their year.
+fn report(options: &Options) -> ExitCode {
+    let answers = match load_answers(options) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (results, _) = solve_jobs(options, &input_jobs(options, Some(&answers)));
+    let warmup = options.warmup.unwrap_or(bench::DEFAULT_WARMUP);
+    let iterations = options.iterations.unwrap_or(bench::DEFAULT_ITERATIONS);
+    let year = options.days[0].year;
+    let layout = scaffold::Layout::for_year(year).ok();
+
+    let rows = options
+        .days
+        .iter()
+        .map(|day| {
+            let parts = results
+                .iter()
+                .filter(|r| r.day == day.day)
+                .map(|r| report::Part {
+                    answer: r.answer.as_ref().ok().map(Answer::to_string),
+                    passed: r.passed(),
+                })
+                .collect();
+            let all_parts = (1..=day.parts).collect::<Vec<_>>();
+            let measurements = read_input(day, None)
+                .and_then(|(text, file)| {
+                    caught(&file, || {
+                        bench::bench_day(
+                            day,
+                            &text,
+                            &all_parts,
+                            &options.params,
+                            warmup,
+                            iterations,
+                        )
+                    })
+                })
+                .unwrap_or_default();
+            let median = |stage: Stage| {
+                measurements
+                    .iter()
+                    .find(|m| m.stage == stage)
+                    .map(|m| m.stats.median)
+            };
+            let lines = layout
+                .as_ref()
+                .and_then(|layout| std::fs::read_to_string(layout.module(day.day)).ok())
+                .map(|source| report::lines_of_code(&source));
+            Row {
+                day: day.day,
+                title: day.title.to_string(),
+                parts,
+                parse: median(Stage::Parse),
+                solve: all_parts
+                    .iter()
+                    .map(|&part| median(Stage::Part(part)))
+                    .sum(),
+                lines,
+            }
+        })
+        .collect();
+    let report = Report {
+        year: year.to_string(),
+        rows,
+        mask: options.mask,
+    };
+    let text = if options.html {
+        report.to_html()
+    } else {
+        report.to_markdown()
+    };
+
+    match &options.output {
+        None => {
+            print!("{text}");
+            ExitCode::SUCCESS
+        }
+        Some(path) => match std::fs::write(path, text) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// Polls the day's files, rebuilding and solving it in a fresh process after
+/// each change. Only stops when interrupted.
+fn watch(options: &Options) -> ExitCode {
+    let day = options.days[0];
+    let layout = match scaffold::Layout::for_year(day.year) {
+        Ok(layout) => layout,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // A rebuild replaces the executable, after which the running one can no
+    // longer find its own path.
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => {
+            eprintln!("error: cannot locate the running executable: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let interval = options.interval.unwrap_or(watch::DEFAULT_INTERVAL);
+
+    let mut snapshot = Snapshot::take(&watch::day_files(&layout, day.day));
+    println!(
+        "watching {} day {} every {}, Ctrl-C to stop",
+        day.year,
+        day.name(),
+        bench::format_duration(interval)
+    );
+    watch_round(options, &exe, &layout);
+    loop {
+        thread::sleep(interval);
+        let latest = Snapshot::take(&watch::day_files(&layout, day.day));
+        let changed = latest.changed_since(&snapshot);
+        if changed.is_empty() {
+            continue;
+        }
+        snapshot = latest;
+        println!();
+        for path in changed {
+            println!("changed: {}", path.display());
+        }
+        watch_round(options, &exe, &layout);
+    }
+}
+
+/// Rebuilds `exe` and has the new build solve the day, or this one when
+/// cargo cannot be run at all.
+fn watch_round(options: &Options, exe: &Path, layout: &scaffold::Layout) {
+    match watch::rebuild(exe, layout) {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            println!("build failed, waiting for the next change");
+            return;
+        }
+        Err(err) => {
+            eprintln!("could not run cargo ({err}), solving with the running build");
+            watch_once(options);
+            return;
+        }
+    }
+    if let Err(err) = process::Command::new(exe).args(once_args(options)).status() {
+        eprintln!("error: could not run {}: {err}", exe.display());
+    }
+}
+
+/// The arguments for a single round of `watch`, with the same day, input
+/// directory and options.
+fn once_args(options: &Options) -> Vec<String> {
+    let day = options.days[0];
+    let mut args = vec![
+        "watch".to_string(),
+        day.year.to_string(),
+        day.name(),
+        "--once".to_string(),
+        input::FLAG.to_string(),
+        input::root().display().to_string(),
+    ];
+    if let Some(part) = options.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(jobs) = options.jobs {
+        args.extend(["--jobs".to_string(), jobs.to_string()]);
+    }
+    if let Some(level) = options.log_level.filter(|&level| level > Level::Off) {
+        args.push(format!("-{}", "v".repeat(level as usize)));
+    }
+    for (name, value) in options.params.iter() {
+        args.extend([format!("--{}", name.replace('_', "-")), value.to_string()]);
+    }
+    args
+}
+
+/// Solves the day's examples and then its real input, printing the time
+/// each part took.
+fn watch_once(options: &Options) -> ExitCode {
+    let answers = match load_answers(options) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut results = Vec::new();
+    for jobs in [
+        example_jobs(options, &answers),
+        input_jobs(options, Some(&answers)),
+    ] {
+        let (solved, _) = solve_jobs(options, &jobs);
+        for result in &solved {
+            println!(
+                "{}: {}  [{}]",
+                result.label(),
+                result.status(),
+                bench::format_duration(result.elapsed)
+            );
+        }
+        results.extend(solved);
+    }
+    let passed = results.iter().filter(|r| r.passed() == Some(true)).count();
+    let failed = results.iter().filter(|r| r.passed() == Some(false)).count();
+    let missing = results.iter().filter(|r| r.passed().is_none()).count();
+    println!("{passed} passed, {failed} failed, {missing} without a stored answer");
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new_day(options: &Options) -> ExitCode {
+    // Default to the latest registered year.
+    let Some(year) = options
+        .year
+        .as_deref()
+        .or(options.years.last().map(|y| y.year))
+    else {
+        eprintln!("error: new needs --year");
+        return ExitCode::FAILURE;
+    };
+    let day = options.day_number.expect("checked when parsing arguments");
+    let created = match scaffold::Layout::for_year(year) {
+        Ok(layout) => layout.create_day(day),
+        Err(_) => scaffold::Layout::for_new_year(year).create_year(day),
+    };
+    match created {
+        Ok(touched) => {
+            if options.json {
+                let paths = touched
+                    .iter()
+                    .map(|p| p.display().to_string().into())
+                    .collect();
+                println!("{}", Json::Array(paths));
+            } else {
+                for path in touched {
+                    println!("Wrote {}", path.display());
+                }
+                println!("Rebuild to run day {day:02}.");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch(_options: &Options) -> ExitCode {
+    eprintln!("error: fetch needs the `fetch` feature; run it from the workspace binary");
+    ExitCode::FAILURE
+}
+
+#[cfg(feature = "fetch")]
+fn fetch(options: &Options) -> ExitCode {
+    let Some(year) = options
+        .year
+        .as_deref()
+        .or(options.years.last().map(|y| y.year))
+    else {
+        eprintln!("error: fetch needs --year");
+        return ExitCode::FAILURE;
+    };
+    let day = options.day_number.expect("checked when parsing arguments");
+    let path = input::path(year, &format!("{day:02}"), None);
+    // No session is needed for an input already there.
+    if fetch::is_cached(&path) {
+        println!("{} is already there", path.display());
+        return ExitCode::SUCCESS;
+    }
+
+    let session = match fetch::session() {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let base_url = options
+        .base_url
+        .clone()
+        .or_else(|| env::var(fetch::BASE_URL_ENV).ok())
+        .unwrap_or(fetch::DEFAULT_BASE_URL.to_string());
+    let mut client = Client::new(&base_url, &session)
+        .with_interval(options.interval.unwrap_or(fetch::DEFAULT_INTERVAL));
+    if let Some(dir) = fetch::config_dir() {
+        client = client.with_stamp(dir.join("last-request"));
+    }
+    match client.fetch(year, day, &path) {
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Wrote {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Cached(path)) => {
+            println!("{} is already there", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn history_path(options: &Options) -> PathBuf {
+    options
+        .history
+        .clone()
+        .unwrap_or_else(history::default_path)
+}
+
+fn compare(options: &Options) -> Result<ExitCode, history::HistoryError> {
+    let history = History::load(&history_path(options))?;
+    let current = match &options.current {
+        Some(commit) => history.resolve(commit)?,
+        None => {
+            let head = history::current_commit();
+            let commits = history.commits();
+            match head.and_then(|head| commits.iter().copied().find(|c| *c == head)) {
+                Some(commit) => commit,
+                None => commits
+                    .last()
+                    .copied()
+                    .ok_or_else(|| history::HistoryError::UnknownCommit("HEAD".to_string()))?,
+            }
+        }
+    };
+    let baseline = match &options.baseline {
+        Some(commit) => history.resolve(commit)?,
+        None => history.previous(current)?,
+    };
+    let threshold = options.threshold.unwrap_or(history::DEFAULT_THRESHOLD);
+    let comparisons = history
+        .compare(baseline, current)
+        .into_iter()
+        .filter(|c| {
+            options
+                .days
+                .iter()
+                .any(|d| d.year == c.year && d.day == c.day)
+        })
+        .filter(|c| match (options.part, c.stage) {
+            (Some(part), Stage::Part(p)) => p == part,
+            (Some(_), Stage::Parse) => false,
+            (None, _) => true,
+        })
+        .collect::<Vec<_>>();
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.regressed(threshold))
+        .count();
+
+    if options.json {
+        let to_json = |c: &Comparison| {
+            Json::object([
+                ("year", c.year.as_str().into()),
+                ("day", c.day.into()),
+                ("stage", c.stage.to_string().into()),
+                ("baseline_ns", (c.baseline.as_nanos() as u64).into()),
+                ("current_ns", (c.current.as_nanos() as u64).into()),
+                ("change_pct", c.change().into()),
+                ("regressed", c.regressed(threshold).into()),
+            ])
+        };
+        println!(
+            "{}",
+            Json::object([
+                ("baseline", baseline.into()),
+                ("current", current.into()),
+                ("threshold_pct", threshold.into()),
+                (
+                    "results",
+                    Json::Array(comparisons.iter().map(to_json).collect())
+                ),
+                ("regressions", regressions.into()),
+            ])
+        );
+    } else {
+        println!("Comparing {current} against baseline {baseline}");
+        for c in &comparisons {
+            println!(
+                "{} day {:02} {:<5}  {:>10} -> {:>10}  {:>+7.1}%{}",
+                c.year,
+                c.day,
+                c.stage.to_string(),
+                bench::format_duration(c.baseline),
+                bench::format_duration(c.current),
+                c.change(),
+                if c.regressed(threshold) {
+                    "  REGRESSION"
+                } else {
+                    ""
+                },
+            );
+        }
+        println!(
+            "{regressions} of {} regressed by more than {threshold}%",
+            comparisons.len()
+        );
+    }
+
+    Ok(if regressions == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn report_run(results: &[PartResult], timing: Timing, json: bool) -> ExitCode {
+    if json {
+        println!(
+            "{}",
+            Json::Array(results.iter().map(PartResult::to_json).collect())
+        );
+    } else {
+        for result in results {
+            match &result.answer {
+                Ok(answer) => println!("{}: {answer}", result.label()),
+                Err(err) => println!("{}: error: {err:#}", result.label()),
+            }
+        }
+        print_timing(results, timing);
+    }
+    if results.iter().all(|r| r.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn report_check(results: &[PartResult], timing: Timing, json: bool) -> ExitCode {
+    let passed = results.iter().filter(|r| r.passed() == Some(true)).count();
+    let failed = results.iter().filter(|r| r.passed() == Some(false)).count();
+    let missing = results.iter().filter(|r| r.passed().is_none()).count();
+
+    if json {
+        println!(
+            "{}",
+            Json::object([
+                (
+                    "results",
+                    Json::Array(results.iter().map(PartResult::to_json).collect())
+                ),
+                ("passed", passed.into()),
+                ("failed", failed.into()),
+                ("missing", missing.into()),
+                ("wall_ns", (timing.wall.as_nanos() as u64).into()),
+                (
+                    "cpu_ns",
+                    timing
+                        .cpu
+                        .map_or(Json::Null, |cpu| (cpu.as_nanos() as u64).into())
+                ),
+                ("jobs", timing.threads.into()),
+            ])
+        );
+    } else {
+        for result in results {
+            println!("{}: {}", result.label(), result.status());
+        }
+        println!("{passed} passed, {failed} failed, {missing} without a stored answer");
+        print_timing(results, timing);
+    }
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn generate(options: &Options) -> ExitCode {
+    let day = options.days[0];
+    let seed = options.seed.unwrap_or(0);
+    match day.generate(seed, options.size.unwrap_or(DEFAULT_GENERATED_SIZE)) {
+        Some(input) => {
+            print!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("error: {} day {} has no generator", day.year, day.name());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// How a day's solver fared against its reference on the generated inputs.
+enum Difftest {
+    /// The number of parts compared, all alike.
+    Agreed(usize),
+    Mismatch(Box<Mismatch>),
+    Panicked(Error),
+}
+
+fn difftest(options: &Options) -> Vec<(&'static Day, Difftest)> {
+    let threads = options.jobs.unwrap_or_else(pool::default_jobs);
+    let first = options.seed.unwrap_or(0);
+    let seeds = first..first + options.count.unwrap_or(differential::DEFAULT_INPUTS);
+    let size = options.size.unwrap_or(differential::DEFAULT_SIZE);
+    let (results, _) = pool::map(threads, &options.days, |day| {
+        let check = || differential::check(day, seeds.clone(), size, &options.params);
+        match panic::catch_unwind(AssertUnwindSafe(check)) {
+            Ok(Ok(compared)) => Difftest::Agreed(compared),
+            Ok(Err(mismatch)) => Difftest::Mismatch(mismatch),
+            Err(payload) => Difftest::Panicked(Error::new(panic_message(payload))),
+        }
+    });
+    options.days.iter().copied().zip(results).collect()
+}
+
+fn report_difftest(results: &[(&Day, Difftest)], json: bool) -> ExitCode {
+    let agreed = results
+        .iter()
+        .all(|(_, result)| matches!(result, Difftest::Agreed(_)));
+    if json {
+        let show = |answer: &Result<Answer, Error>| match answer {
+            Ok(answer) => answer.to_string().into(),
+            Err(err) => Json::object([("error", err.to_string().into())]),
+        };
+        let days = results.iter().map(|(day, result)| {
+            let (compared, mismatch, error) = match result {
+                Difftest::Agreed(compared) => (Some(*compared), None, None),
+                Difftest::Mismatch(mismatch) => {
+                    let mismatch = Json::object([
+                        ("part", mismatch.disagreement.part.into()),
+                        ("seed", mismatch.seed.into()),
+                        ("size", mismatch.size.into()),
+                        ("input", mismatch.input.clone().into()),
+                        ("answer", show(&mismatch.disagreement.answer)),
+                        ("reference", show(&mismatch.disagreement.reference)),
+                    ]);
+                    (None, Some(mismatch), None)
+                }
+                Difftest::Panicked(err) => (None, None, Some(err.to_string())),
+            };
+            Json::object([
+                ("year", day.year.into()),
+                ("day", day.day.into()),
+                ("compared", compared.into()),
+                ("mismatch", mismatch.into()),
+                ("error", error.into()),
+            ])
+        });
+        println!("{}", Json::Array(days.collect()));
+    } else {
+        for (day, result) in results {
+            let label = format!("{} day {}", day.year, day.name());
+            match result {
+                Difftest::Agreed(0) => println!("{label}: nothing to compare"),
+                Difftest::Agreed(compared) => println!("{label}: {compared} parts agree"),
+                Difftest::Mismatch(mismatch) => println!("{label}: {mismatch}"),
+                Difftest::Panicked(err) => println!("{label}: error: {err}"),
+            }
+        }
+    }
+    if agreed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The violations of every selected day's input, or why it could not be
+/// parsed.
+fn validate(options: &Options) -> Vec<(&'static Day, Result<Vec<Error>, Error>)> {
+    let threads = options.jobs.unwrap_or_else(pool::default_jobs);
+    let jobs = input_jobs(options, None);
+    let (violations, _) = pool::map(threads, &jobs, |job| {
+        let (_, text, file) = job.parse(options.input.as_deref())?;
+        job.validate(&text, &file, &options.params)
+    });
+    jobs.iter().map(|job| job.day).zip(violations).collect()
+}
+
+fn report_validation(results: &[(&Day, Result<Vec<Error>, Error>)], json: bool) -> ExitCode {
+    let valid = results
+        .iter()
+        .all(|(_, violations)| violations.as_ref().is_ok_and(Vec::is_empty));
+    if json {
+        let days = results.iter().map(|(day, violations)| {
+            let (error, violations) = match violations {
+                Ok(violations) => (None, violations.iter().map(Error::to_string).collect()),
+                Err(err) => (Some(err.to_string()), Vec::new()),
+            };
+            Json::object([
+                ("year", day.year.into()),
+                ("day", day.day.into()),
+                ("error", error.into()),
+                ("violations", violations.into()),
+            ])
+        });
+        println!("{}", Json::Array(days.collect()));
+    } else {
+        for (day, violations) in results {
+            let label = format!("{} day {}", day.year, day.name());
+            match violations {
+                Err(err) => println!("{label}: error: {err:#}"),
+                Ok(violations) if violations.is_empty() => println!("{label}: ok"),
+                Ok(violations) => {
+                    for violation in violations {
+                        println!("{label}: {violation:#}");
+                    }
+                }
+            }
+        }
+    }
+    if valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The wall time of a run against the CPU time spent parsing and solving,
+/// summed over the threads, which shows how much `--jobs` bought.
+fn print_timing(results: &[PartResult], timing: Timing) {
+    if results.len() > 1 {
+        let cpu = timing
+            .cpu
+            .map_or("unknown".to_string(), bench::format_duration);
+        println!(
+            "{} parts in {} wall time, {} CPU time over {} job{}",
+            results.len(),
+            bench::format_duration(timing.wall),
+            cpu,
+            timing.threads,
+            if timing.threads == 1 { "" } else { "s" }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 6;
+        type Input = Vec<u64>;
+        type Output1 = u64;
+        type Output2 = u64;
+
+        fn parse(input: &str) -> Result<Vec<u64>, Error> {
+            input
+                .split_whitespace()
+                .map(|n| crate::error::parse_at(input, n))
+                .collect()
+        }
+
+        fn validate(input: &str, _params: &Params) -> Vec<Error> {
+            assert!(!input.contains("99"), "validation gave up on 99");
+            input
+                .split_whitespace()
+                .filter(|n| *n == "0")
+                .map(|n| Error::new("zero makes the product trivial").at(input, n))
+                .collect()
+        }
+
+        fn part_1(input: &Vec<u64>, _params: &Params) -> Result<u64, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_2(input: &Vec<u64>, _params: &Params) -> Result<u64, Error> {
+            Ok(input.iter().product())
+        }
+    }
+
+    struct Area;
+
+    impl Solution for Area {
+        const DAY: u8 = 14;
+        const PARAMS: &'static [&'static str] = &["width", "height"];
+        type Input = ();
+        type Output1 = i64;
+        type Output2 = i64;
+
+        fn parse(_input: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn part_1(_input: &(), params: &Params) -> Result<i64, Error> {
+            Ok(params.get("width", 101)? * params.get("height", 103)?)
+        }
+
+        fn part_2(_input: &(), params: &Params) -> Result<i64, Error> {
+            Ok(params.get("width", 101)? + params.get("height", 103)?)
+        }
+    }
+
+    static Y2023: Year = Year {
+        year: "2023",
+        days: &[Day::of::<Sum>("2023")],
+        answers: "",
+    };
+
+    static Y2024: Year = Year {
+        year: "2024",
+        days: &[Day::of::<Sum>("2024"), Day::of::<Area>("2024")],
+        answers: "",
+    };
+
+    static ONE_YEAR: &[&Year] = &[&Y2024];
+    static TWO_YEARS: &[&Year] = &[&Y2023, &Y2024];
+
+    fn args(a: &str) -> Vec<String> {
+        a.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn parse_run_with_params() {
+        let (command, options) =
+            parse_args(&args("run 14 --part 1 --width 11 --height 7"), ONE_YEAR)
+                .expect("valid arguments");
+        assert_eq!(command, Command::Run);
+        assert_eq!(options.days.len(), 1);
+        assert_eq!(options.days[0].day, 14);
+        assert_eq!(options.part, Some(1));
+        assert_eq!(
+            options.params,
+            Params::new().with("width", 11).with("height", 7)
+        );
+    }
+
+    #[test]
+    fn parse_check_defaults_to_all_days() {
+        let (command, options) =
+            parse_args(&args("--json check"), TWO_YEARS).expect("valid arguments");
+        assert_eq!(command, Command::Check);
+        assert!(options.json);
+        assert_eq!(options.days.len(), 3);
+        assert_eq!(options.log_level, None);
+    }
+
+    #[test]
+    fn parse_verbosity() {
+        let level = |a: &str| parse_args(&args(a), ONE_YEAR).unwrap().1.log_level;
+        assert_eq!(level("run 6 -v"), Some(Level::Info));
+        assert_eq!(level("-vv run 6"), Some(Level::Debug));
+        assert_eq!(level("run 6 -vvvv"), Some(Level::Trace));
+        assert_eq!(level("run 6 --verbose"), Some(Level::Info));
+    }
+
+    #[test]
+    fn parse_bench_counts() {
+        let (command, options) = parse_args(&args("bench --warmup 0 --iterations 5"), ONE_YEAR)
+            .expect("valid arguments");
+        assert_eq!(command, Command::Bench);
+        assert_eq!(options.warmup, Some(0));
+        assert_eq!(options.iterations, Some(5));
+        assert_eq!(options.days.len(), 2);
+    }
+
+    #[test]
+    fn parse_generated_input_options() {
+        let (command, options) =
+            parse_args(&args("generate 6 --seed 7 --size 20"), ONE_YEAR).expect("valid arguments");
+        assert_eq!(command, Command::Generate);
+        assert_eq!(options.days.len(), 1);
+        assert_eq!((options.seed, options.size), (Some(7), Some(20)));
+
+        let (command, options) =
+            parse_args(&args("difftest --count 5"), ONE_YEAR).expect("valid arguments");
+        assert_eq!(command, Command::Difftest);
+        assert_eq!((options.days.len(), options.count), (2, Some(5)));
+    }
+
+    #[test]
+    fn parse_report_options() {
+        let (command, options) = parse_args(
+            &args("report 2023 --mask --html --output report.html"),
+            TWO_YEARS,
+        )
+        .expect("valid arguments");
+        assert_eq!(command, Command::Report);
+        assert!(options.days.iter().all(|d| d.year == "2023"));
+        assert!(options.mask && options.html);
+        assert_eq!(options.output, Some(PathBuf::from("report.html")));
+    }
+
+    #[test]
+    fn parse_fetch_options() {
+        let (command, options) = parse_args(
+            &args("fetch --year 2025 --day 3 --base-url http://localhost:8080"),
+            ONE_YEAR,
+        )
+        .expect("valid arguments");
+        assert_eq!(command, Command::Fetch);
+        assert_eq!(options.year.as_deref(), Some("2025"));
+        assert_eq!(options.day_number, Some(3));
+        assert_eq!(options.base_url.as_deref(), Some("http://localhost:8080"));
+    }
+
+    #[test]
+    fn watch_rounds_keep_the_options() {
+        let (command, options) = parse_args(
+            &args("watch 14 --part 2 --interval 200 -vv --width 11"),
+            ONE_YEAR,
+        )
+        .expect("valid arguments");
+        assert_eq!(command, Command::Watch);
+        assert_eq!(options.interval, Some(Duration::from_millis(200)));
+        assert!(!options.once);
+
+        let mut once = once_args(&options);
+        // Passing the input directory on would override it for every test.
+        assert_eq!(once[4], input::FLAG);
+        once.drain(4..6);
+        assert_eq!(
+            once.join(" "),
+            "watch 2024 14 --once --part 2 -vv --width 11"
+        );
+        let (command, again) = parse_args(&once, ONE_YEAR).expect("valid arguments");
+        assert_eq!(command, Command::Watch);
+        assert!(again.once);
+        assert_eq!(again.days[0].day, 14);
+        assert_eq!(again.params, options.params);
+    }
+
+    #[test]
+    fn parse_year_and_day() {
+        let (_, options) = parse_args(&args("run 2023 06"), TWO_YEARS).expect("valid arguments");
+        assert_eq!(options.days.len(), 1);
+        assert_eq!((options.days[0].year, options.days[0].day), ("2023", 6));
+
+        let (_, options) = parse_args(&args("run 2024"), TWO_YEARS).expect("valid arguments");
+        assert!(options.days.iter().all(|d| d.year == "2024"));
+        assert_eq!(options.days.len(), 2);
+
+        let (_, options) = parse_args(&args("run --all"), TWO_YEARS).expect("valid arguments");
+        assert_eq!(options.days.len(), 3);
+        assert_eq!(options.jobs, None);
+
+        let (_, options) = parse_args(&args("check --jobs 3"), TWO_YEARS).expect("valid arguments");
+        assert_eq!(options.jobs, Some(3));
+    }
+
+    #[test]
+    fn reject_bad_arguments() {
+        let rejected = |a: &str, years| parse_args(&args(a), years).is_err();
+        assert!(rejected("run", ONE_YEAR));
+        assert!(rejected("run 26", ONE_YEAR));
+        assert!(rejected("run 6", TWO_YEARS));
+        assert!(rejected("run 2025 6", TWO_YEARS));
+        assert!(rejected("run 2023 14", TWO_YEARS));
+        assert!(rejected("run 2024 6 7", TWO_YEARS));
+        assert!(rejected("run 6 --part 3", ONE_YEAR));
+        assert!(rejected("run 6 --width 11", ONE_YEAR));
+        assert!(rejected("run --all --input -", ONE_YEAR));
+        assert!(rejected("bench 6 --iterations many", ONE_YEAR));
+        assert!(rejected("bench 6 --iterations 0", ONE_YEAR));
+        assert!(rejected("check --jobs 0", ONE_YEAR));
+        assert!(rejected("generate", ONE_YEAR));
+        assert!(rejected("generate 6 --seed -1", ONE_YEAR));
+        assert!(rejected("report 6", ONE_YEAR));
+        assert!(rejected("report", TWO_YEARS));
+        assert!(rejected("watch", ONE_YEAR));
+        assert!(rejected("fetch", ONE_YEAR));
+        assert!(rejected("fetch --day 26", ONE_YEAR));
+        assert!(rejected("fetch 6 --day 6", ONE_YEAR));
+        assert!(rejected("fetch --day 6 --year 24", ONE_YEAR));
+        assert!(rejected("watch 6 --interval 0", ONE_YEAR));
+        assert!(rejected("compare --threshold fast", ONE_YEAR));
+        assert!(rejected("new --year 2024", ONE_YEAR));
+        assert!(rejected("new --day 1 --year 25", ONE_YEAR));
+        assert!(rejected("frobnicate", ONE_YEAR));
+    }
+
+    #[test]
+    fn errors_name_the_input_file() {
+        let path = env::temp_dir().join(format!("aoc-runner-{}.input", std::process::id()));
+        std::fs::write(&path, "1 2\n3 x\n").unwrap();
+        let (_, options) = parse_args(
+            &args(&format!("run 6 --part 1 --input {}", path.display())),
+            ONE_YEAR,
+        )
+        .expect("valid arguments");
+        let (results, _) = solve_jobs(&options, &input_jobs(&options, None));
+        std::fs::remove_file(&path).unwrap();
+
+        let err = results[0].answer.as_ref().unwrap_err();
+        assert_eq!(err.file, Some(path.display().to_string()));
+        assert_eq!(err.position, Some((2, 3)));
+    }
+
+    #[test]
+    fn panicking_validation_is_an_error() {
+        let path = env::temp_dir().join(format!("aoc-panic-{}.input", std::process::id()));
+        std::fs::write(&path, "1 99\n").unwrap();
+        let (_, options) = parse_args(
+            &args(&format!("run 6 --input {}", path.display())),
+            ONE_YEAR,
+        )
+        .expect("valid arguments");
+        let (results, _) = solve_jobs(&options, &input_jobs(&options, None));
+        std::fs::remove_file(&path).unwrap();
+        for result in results {
+            let err = result.answer.unwrap_err();
+            assert!(
+                err.to_string().contains("validation gave up on 99"),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn violations_are_reported_before_solving() {
+        let path = env::temp_dir().join(format!("aoc-validate-{}.input", std::process::id()));
+        std::fs::write(&path, "1 2\n0 4\n").unwrap();
+        let input = format!("--input {}", path.display());
+        let (_, options) =
+            parse_args(&args(&format!("validate 6 {input}")), ONE_YEAR).expect("valid arguments");
+        let validation = validate(&options);
+        let (_, options) =
+            parse_args(&args(&format!("run 6 {input}")), ONE_YEAR).expect("valid arguments");
+        let (results, _) = solve_jobs(&options, &input_jobs(&options, None));
+        std::fs::remove_file(&path).unwrap();
+
+        let violations = validation[0].1.as_ref().unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].position, Some((2, 1)));
+        for result in results {
+            assert_eq!(result.answer, Err(violations[0].clone()));
+        }
+    }
+}
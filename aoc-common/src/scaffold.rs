@@ -0,0 +1,599 @@
+//! The `new` command: instantiates `template_rs` for a new day and registers
+//! it with the runner, first creating the year's crate from `template_year`
+//! if the workspace has none.
+//!
+//! Every target is checked before anything is written, and whatever was
+//! written is undone if a later step fails.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::answers::UNKNOWN_ANSWER;
+use crate::input;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    UnknownYear(String),
+    InvalidDay(u8),
+    Exists(PathBuf),
+    NoRegistry(PathBuf, &'static str),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::UnknownYear(year) => write!(f, "no aoc{year} crate in the workspace"),
+            ScaffoldError::InvalidDay(day) => write!(f, "day must be between 1 and 25, got {day}"),
+            ScaffoldError::Exists(path) => {
+                write!(
+                    f,
+                    "{} already exists, refusing to overwrite it",
+                    path.display()
+                )
+            }
+            ScaffoldError::NoRegistry(path, what) => {
+                write!(f, "could not find {what} in {}", path.display())
+            }
+            ScaffoldError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Where a year's sources, inputs and answers live.
+#[derive(Clone, Debug)]
+pub struct Layout {
+    pub year: String,
+    pub workspace: PathBuf,
+    pub crate_dir: PathBuf,
+    pub template: PathBuf,
+    pub input_root: PathBuf,
+    pub answers: PathBuf,
+}
+
+impl Layout {
+    pub fn for_year(year: &str) -> Result<Layout, ScaffoldError> {
+        let layout = Layout::for_new_year(year);
+        if !layout.crate_dir.join("src").join("lib.rs").is_file() {
+            return Err(ScaffoldError::UnknownYear(year.to_string()));
+        }
+        Ok(layout)
+    }
+
+    /// The layout of a year whose crate may not exist yet.
+    pub fn for_new_year(year: &str) -> Layout {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let workspace = manifest_dir.parent().unwrap_or(manifest_dir);
+        let crate_dir = workspace.join(format!("aoc{year}"));
+        let input_root = input::root();
+        let answers = match input_root.join(year).join("answers.txt") {
+            path if path.is_file() => path,
+            _ => crate_dir.join("answers.txt"),
+        };
+        Layout {
+            year: year.to_string(),
+            workspace: workspace.to_path_buf(),
+            template: workspace.join("template_rs"),
+            crate_dir,
+            input_root,
+            answers,
+        }
+    }
+
+    /// The source file of a day's module.
+    pub fn module(&self, day: u8) -> PathBuf {
+        self.crate_dir.join("src").join(format!("day{day:02}.rs"))
+    }
+
+    /// Writes the module and binary for `day`, registers it, and adds empty
+    /// inputs and placeholder answers. Nothing is left written if the day
+    /// exists or a step fails.
+    pub fn create_day(&self, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let mut changes = Changes::default();
+        match self.write_day(day, None, &mut changes) {
+            Ok(()) => Ok(changes.touched),
+            Err(err) => {
+                changes.undo();
+                Err(err)
+            }
+        }
+    }
+
+    /// Creates the year's crate from `template_year`, adds it to the
+    /// workspace and the dispatcher in `src/main.rs`, and then `day` in it.
+    /// Nothing is left written if the crate exists or a step fails.
+    pub fn create_year(&self, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let mut changes = Changes::default();
+        match self.write_year(day, &mut changes) {
+            Ok(()) => Ok(changes.touched),
+            Err(err) => {
+                changes.undo();
+                Err(err)
+            }
+        }
+    }
+
+    fn write_year(&self, day: u8, changes: &mut Changes) -> Result<(), ScaffoldError> {
+        if self.crate_dir.exists() {
+            return Err(ScaffoldError::Exists(self.crate_dir.clone()));
+        }
+        let template_dir = self.workspace.join("template_year");
+        let files = YEAR_TEMPLATE
+            .iter()
+            .map(|file| {
+                let source = read(&template_dir.join(file))?;
+                Ok((
+                    self.crate_dir.join(file),
+                    source.replace("YYYY", &self.year),
+                ))
+            })
+            .collect::<Result<Vec<_>, ScaffoldError>>()?;
+        let name = format!("aoc{}", self.year);
+        let manifest = self.workspace.join("Cargo.toml");
+        let manifest_source = read(&manifest)?;
+        let manifest_registry = add_member(&manifest_source, &name).ok_or(
+            ScaffoldError::NoRegistry(manifest.clone(), "the workspace members"),
+        )?;
+        let main = self.workspace.join("src").join("main.rs");
+        let main_source = read(&main)?;
+        let main_registry = add_year(&main_source, &name)
+            .ok_or(ScaffoldError::NoRegistry(main.clone(), "the `YEARS` list"))?;
+        let lib = files
+            .iter()
+            .find(|(path, _)| path.ends_with("src/lib.rs"))
+            .map(|(_, source)| source.as_str())
+            .unwrap_or_default();
+        self.check_day(day, lib)?;
+
+        for (path, source) in &files {
+            changes.create(path, source)?;
+        }
+        changes.rewrite(&manifest, &manifest_source, &manifest_registry)?;
+        changes.rewrite(&main, &main_source, &main_registry)?;
+        self.write_day(day, Some(lib), changes)
+    }
+
+    /// Checks that `day` can be added to the registry in `lib`, returning
+    /// the registry with it.
+    fn check_day(&self, day: u8, lib: &str) -> Result<String, ScaffoldError> {
+        if !(1..=25).contains(&day) {
+            return Err(ScaffoldError::InvalidDay(day));
+        }
+        let name = format!("{day:02}");
+        let module = self.module(day);
+        let bin = self
+            .crate_dir
+            .join("src")
+            .join("bin")
+            .join(format!("{name}.rs"));
+        for path in [&module, &bin] {
+            if path.exists() {
+                return Err(ScaffoldError::Exists(path.clone()));
+            }
+        }
+        if lib.contains(&format!("day{name}::")) {
+            return Err(ScaffoldError::Exists(module));
+        }
+        let path = self.crate_dir.join("src").join("lib.rs");
+        register(lib, day).ok_or(ScaffoldError::NoRegistry(path, "the `days!` registry"))
+    }
+
+    /// Adds `day` to the crate, whose `lib.rs` is `lib` if only just written.
+    fn write_day(
+        &self,
+        day: u8,
+        lib: Option<&str>,
+        changes: &mut Changes,
+    ) -> Result<(), ScaffoldError> {
+        let name = format!("{day:02}");
+        let src = self.crate_dir.join("src");
+        let module = self.module(day);
+        let bin = src.join("bin").join(format!("{name}.rs"));
+        let lib_path = src.join("lib.rs");
+        let lib_source = match lib {
+            Some(lib) => lib.to_string(),
+            None => read(&lib_path)?,
+        };
+        let registry = self.check_day(day, &lib_source)?;
+        let template = read(&self.template)?;
+        let answers = match self.answers.exists() {
+            true => Some(read(&self.answers)?),
+            false => None,
+        };
+
+        changes.create(&module, &render(&template, day))?;
+        changes.create(
+            &bin,
+            &format!(
+                "use aoc{year}::day{name}::Day{name};\n\nfn main() {{\n    aoc{year}::solution::main::<Day{name}>();\n}}\n",
+                year = self.year
+            ),
+        )?;
+        changes.rewrite(&lib_path, &lib_source, &registry)?;
+
+        for variant in [None, Some("example")] {
+            let path = input::path_in(&self.input_root, &self.year, &name, variant);
+            if !path.exists() {
+                changes.create(&path, "")?;
+            }
+        }
+
+        let mut entries = String::new();
+        for part in 1..=2 {
+            for variant in ["example", "input"] {
+                entries.push_str(&format!("{name} {part} {variant} {UNKNOWN_ANSWER}\n"));
+            }
+        }
+        match answers {
+            Some(answers) => {
+                changes.rewrite(&self.answers, &answers, &(answers.clone() + &entries))
+            }
+            None => changes.create(&self.answers, &entries),
+        }
+    }
+}
+
+/// The files of `template_year`, relative to it and to the new crate.
+const YEAR_TEMPLATE: &[&str] = &[
+    "Cargo.toml",
+    "build.rs",
+    "answers.txt",
+    "src/lib.rs",
+    "src/solution.rs",
+    "src/main.rs",
+];
+
+/// What has been written so far, to report or else to undo.
+#[derive(Default)]
+struct Changes {
+    touched: Vec<PathBuf>,
+    undo: Vec<Undo>,
+}
+
+enum Undo {
+    Remove(PathBuf),
+    RemoveDir(PathBuf),
+    Restore(PathBuf, String),
+}
+
+impl Changes {
+    /// Writes a new file, along with any directories it needs.
+    fn create(&mut self, path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+        let missing = path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .collect::<Vec<_>>();
+        for dir in missing.into_iter().rev() {
+            fs::create_dir(dir).map_err(|err| ScaffoldError::Io(dir.to_path_buf(), err))?;
+            self.undo.push(Undo::RemoveDir(dir.to_path_buf()));
+        }
+        write_new(path, contents)?;
+        self.undo.push(Undo::Remove(path.to_path_buf()));
+        self.touched.push(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Replaces the `original` contents of a file.
+    fn rewrite(
+        &mut self,
+        path: &Path,
+        original: &str,
+        contents: &str,
+    ) -> Result<(), ScaffoldError> {
+        self.undo
+            .push(Undo::Restore(path.to_path_buf(), original.to_string()));
+        fs::write(path, contents).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))?;
+        if !self.touched.iter().any(|touched| touched == path) {
+            self.touched.push(path.to_path_buf());
+        }
+        Ok(())
+    }
+
+    /// Undoes every change, latest first. Failures are ignored, as the
+    /// error that led here is the one worth reporting.
+    fn undo(self) {
+        for undo in self.undo.into_iter().rev() {
+            let _ = match undo {
+                Undo::Remove(path) => fs::remove_file(path),
+                Undo::RemoveDir(dir) => fs::remove_dir(dir),
+                Undo::Restore(path, contents) => fs::write(path, contents),
+            };
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| ScaffoldError::Io(dir.to_path_buf(), err))?;
+    }
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::AlreadyExists => ScaffoldError::Exists(path.to_path_buf()),
+            _ => ScaffoldError::Io(path.to_path_buf(), err),
+        })?;
+    file.write_all(contents.as_bytes())
+        .map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+/// Substitutes the day into the template. `DAY` takes the bare number, as
+/// zero-prefixed literals trip clippy, and every other `NN` the padded one.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("const DAY: u8 = NN;", &format!("const DAY: u8 = {day};"))
+        .replace("NN", &format!("{day:02}"))
+}
+
+/// Adds `pub mod dayDD;` to the module declarations just above the `days!`
+/// invocation, and `dayDD::DayDD` to the invocation, keeping both sorted.
+fn register(lib: &str, day: u8) -> Option<String> {
+    let invocation = lib.find("\ndays! {\n")? + 1;
+    let before = &lib[..invocation - 1];
+    let mut mods_start = before.len();
+    while let Some(rest) = before[..mods_start].strip_suffix('\n') {
+        let line_start = rest.rfind('\n').map_or(0, |at| at + 1);
+        if !before[line_start..].starts_with("pub mod day") {
+            break;
+        }
+        mods_start = line_start;
+    }
+    let module = format!("pub mod day{day:02};");
+    let mut modules: Vec<&str> = before[mods_start..].lines().collect();
+    modules.push(&module);
+    modules.sort();
+
+    let start = invocation + "days! {\n".len();
+    let end = start + lib[start..].find("}\n")?;
+    let entry = format!("day{day:02}::Day{day:02},");
+    let mut entries: Vec<&str> = lib[start..end].lines().map(str::trim).collect();
+    entries.push(&entry);
+    entries.sort();
+
+    let mut registry = lib[..mods_start].to_string();
+    if mods_start == before.len() {
+        registry.push('\n');
+    }
+    for module in modules {
+        registry.push_str(&format!("{module}\n"));
+    }
+    registry.push_str("\ndays! {\n");
+    for entry in entries {
+        registry.push_str(&format!("    {entry}\n"));
+    }
+    registry.push_str(&lib[end..]);
+    Some(registry)
+}
+
+/// Adds `name` to the workspace members in the root `Cargo.toml`, and as a
+/// dependency of the dispatcher after the other years.
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+    let entry = format!("\"{name}\"");
+    let mut members: Vec<&str> = manifest[start..end].split(',').map(str::trim).collect();
+    members.push(&entry);
+    members.sort();
+    let mut manifest = format!(
+        "{}{}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    );
+
+    let dependency = format!("{name} = {{ path = \"{name}\" }}\n");
+    let section = manifest.find("[dependencies]\n")? + "[dependencies]\n".len();
+    let mut at = section;
+    for line in manifest[section..].split_inclusive('\n') {
+        if line.trim().is_empty() || line.starts_with('[') {
+            break;
+        }
+        if is_year_crate(line) && line > dependency.as_str() {
+            break;
+        }
+        at += line.len();
+    }
+    manifest.insert_str(at, &dependency);
+    Some(manifest)
+}
+
+fn is_year_crate(line: &str) -> bool {
+    line.strip_prefix("aoc")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Adds the year to the `YEARS` list of the dispatcher, keeping it sorted.
+fn add_year(main: &str, name: &str) -> Option<String> {
+    let start = main.find("static YEARS: &[&Year] = &[")? + "static YEARS: &[&Year] = &[".len();
+    let end = start + main[start..].find("];")?;
+    let entry = format!("&{name}::PUZZLES");
+    let mut years: Vec<&str> = main[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|year| !year.is_empty())
+        .collect();
+    years.push(&entry);
+    years.sort();
+    Some(format!(
+        "{}{}{}",
+        &main[..start],
+        years.join(", "),
+        &main[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod solution;\n\npub mod day01;\npub mod day03;\n\n\
+                       days! {\n    day01::Day01,\n    day03::Day03,\n}\n\npub fn get_day() {}\n";
+
+    #[test]
+    fn render_template() {
+        let rendered = render("pub struct DayNN;\nconst DAY: u8 = NN;\n", 7);
+        assert_eq!(rendered, "pub struct Day07;\nconst DAY: u8 = 7;\n");
+    }
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let lib = register(LIB, 2).unwrap();
+        assert_eq!(
+            lib,
+            "pub mod solution;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\n\
+             days! {\n    day01::Day01,\n    day02::Day02,\n    day03::Day03,\n}\n\n\
+             pub fn get_day() {}\n"
+        );
+        let lib = register("pub mod solution;\n\ndays! {\n}\n", 1).unwrap();
+        assert_eq!(
+            lib,
+            "pub mod solution;\n\npub mod day01;\n\ndays! {\n    day01::Day01,\n}\n"
+        );
+        assert_eq!(register("fn main() {}\n", 2), None);
+    }
+
+    #[test]
+    fn create_day_refuses_to_overwrite() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let crate_dir = dir.join("aoc2024");
+        fs::create_dir_all(crate_dir.join("src")).unwrap();
+        fs::write(crate_dir.join("src").join("lib.rs"), LIB).unwrap();
+        fs::write(dir.join("template_rs"), "pub struct DayNN;\n").unwrap();
+        let layout = Layout {
+            year: "2024".to_string(),
+            workspace: dir.clone(),
+            template: dir.join("template_rs"),
+            input_root: dir.join("input"),
+            answers: crate_dir.join("answers.txt"),
+            crate_dir,
+        };
+
+        let touched = layout.create_day(2).unwrap();
+        assert_eq!(touched.len(), 6);
+        assert_eq!(
+            fs::read_to_string(layout.crate_dir.join("src").join("day02.rs")).unwrap(),
+            "pub struct Day02;\n"
+        );
+        assert!(dir.join("input/2024/02.input.example").is_file());
+        assert_eq!(
+            fs::read_to_string(&layout.answers).unwrap().lines().count(),
+            4
+        );
+
+        assert!(matches!(
+            layout.create_day(2),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert!(matches!(
+            layout.create_day(26),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+
+        // A step failing after others have written undoes them.
+        let answers = fs::read_to_string(&layout.answers).unwrap();
+        let layout = Layout {
+            input_root: dir.join("template_rs"),
+            ..layout
+        };
+        assert!(matches!(layout.create_day(4), Err(ScaffoldError::Io(..))));
+        assert!(!layout.module(4).exists());
+        assert!(!layout.crate_dir.join("src/bin/04.rs").exists());
+        assert_eq!(fs::read_to_string(&layout.answers).unwrap(), answers);
+        assert!(
+            !fs::read_to_string(layout.crate_dir.join("src/lib.rs"))
+                .unwrap()
+                .contains("day04")
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn add_member_and_year_keep_years_sorted() {
+        let manifest = "[package]\nname = \"root\"\n\n[dependencies]\naoc-common = { path = \"aoc-common\" }\naoc2024 = { path = \"aoc2024\" }\n\n[workspace]\nmembers = [\"aoc-common\", \"aoc2024\"]\n";
+        assert_eq!(
+            add_member(manifest, "aoc2023").unwrap(),
+            "[package]\nname = \"root\"\n\n[dependencies]\naoc-common = { path = \"aoc-common\" }\naoc2023 = { path = \"aoc2023\" }\naoc2024 = { path = \"aoc2024\" }\n\n[workspace]\nmembers = [\"aoc-common\", \"aoc2023\", \"aoc2024\"]\n"
+        );
+        assert_eq!(add_member("[package]\n", "aoc2025"), None);
+
+        let main = "static YEARS: &[&Year] = &[&aoc2024::PUZZLES];\n";
+        assert_eq!(
+            add_year(main, "aoc2025").unwrap(),
+            "static YEARS: &[&Year] = &[&aoc2024::PUZZLES, &aoc2025::PUZZLES];\n"
+        );
+    }
+
+    #[test]
+    fn create_year_writes_and_registers_the_crate() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-year-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let templates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for file in YEAR_TEMPLATE {
+            let path = dir.join("template_year").join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(templates.join("template_year").join(file), path).unwrap();
+        }
+        fs::write(dir.join("template_rs"), "pub struct DayNN;\n").unwrap();
+        let manifest = "[dependencies]\naoc2024 = { path = \"aoc2024\" }\n\n[workspace]\nmembers = [\"aoc2024\"]\n";
+        fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let main = "static YEARS: &[&Year] = &[&aoc2024::PUZZLES];\n";
+        fs::write(dir.join("src/main.rs"), main).unwrap();
+        let layout = Layout {
+            year: "2025".to_string(),
+            workspace: dir.clone(),
+            template: dir.join("template_rs"),
+            input_root: dir.join("input"),
+            answers: dir.join("aoc2025/answers.txt"),
+            crate_dir: dir.join("aoc2025"),
+        };
+
+        // A bad day leaves the workspace as it was.
+        assert!(matches!(
+            layout.create_year(26),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+        assert!(!layout.crate_dir.exists());
+
+        let touched = layout.create_year(1).unwrap();
+        assert_eq!(touched.len(), YEAR_TEMPLATE.len() + 2 + 4);
+        let lib = fs::read_to_string(layout.crate_dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub const YEAR: &str = \"2025\";"));
+        assert!(lib.contains("}\n\npub mod day01;\n\ndays! {\n    day01::Day01,\n}"));
+        assert!(
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .unwrap()
+                .contains("members = [\"aoc2024\", \"aoc2025\"]")
+        );
+        assert!(
+            fs::read_to_string(dir.join("src/main.rs"))
+                .unwrap()
+                .contains("&aoc2025::PUZZLES")
+        );
+        assert_eq!(
+            fs::read_to_string(&layout.answers)
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with("01 "))
+                .count(),
+            4
+        );
+
+        assert!(matches!(
+            layout.create_year(2),
+            Err(ScaffoldError::Exists(_))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
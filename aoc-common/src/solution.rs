@@ -0,0 +1,409 @@
+//! The `Solution` trait implemented by every day, and the type-erased [`Day`]
+//! entries that make up each [`Year`].
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::process;
+
+use crate::answers::{self, Answers};
+use crate::error::{Error, Result};
+use crate::input;
+use crate::log;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as _)
+            }
+        })*
+    };
+}
+
+answer_from!(Unsigned: u8, u16, u32, u64, usize);
+answer_from!(Signed: i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Named integer parameters for days whose puzzle depends on more than the
+/// input, e.g. the grid size of day 14. Solvers fall back to the puzzle's
+/// values for anything not set.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn with(mut self, name: &str, value: i64) -> Params {
+        self.set(name, value);
+        self
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.0.insert(name.to_string(), value);
+    }
+
+    /// The parameter `name`, or `default` when it is not set. A value that
+    /// does not fit in `T`, such as a negative size, is an error.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T> {
+        match self.0.get(name) {
+            None => Ok(default),
+            Some(&value) => T::try_from(value).map_err(|_| {
+                Error::new(format!(
+                    "parameter --{} out of range: {value}",
+                    name.replace('_', "-")
+                ))
+            }),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (k, (name, value)) in self.iter().enumerate() {
+            if k > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Solution {
+    const DAY: u8;
+    /// The puzzle's title, for `report`.
+    const TITLE: &'static str = "";
+    /// Number of parts; day 25 only has one.
+    const PARTS: u8 = 2;
+    /// Names of the parameters read from [`Params`].
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// Shared between the threads solving each part.
+    type Input: Send + Sync + 'static;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_1(input: &Self::Input, params: &Params) -> Result<Self::Output1>;
+    fn part_2(input: &Self::Input, params: &Params) -> Result<Self::Output2>;
+
+    /// Checks the assumptions the solver makes about an input that parses,
+    /// beyond what `parse` enforces, e.g. that a map has a single guard.
+    fn validate(_input: &str, _params: &Params) -> Vec<Error> {
+        Vec::new()
+    }
+
+    /// A random input of the given `size`, in whatever unit suits the day
+    /// (lines, grid side, ...), always the same for the same `seed`. `None`
+    /// for days without a generator.
+    fn generate(_seed: u64, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// A slow but plainly correct part 1, to test a solver that takes
+    /// shortcuts against, see [`differential`](crate::differential). `None`
+    /// without one, or for an input too large to brute force.
+    fn reference_1(_input: &Self::Input, _params: &Params) -> Option<Result<Self::Output1>> {
+        None
+    }
+
+    /// The same as [`reference_1`](Solution::reference_1) for part 2.
+    fn reference_2(_input: &Self::Input, _params: &Params) -> Option<Result<Self::Output2>> {
+        None
+    }
+
+    /// Parses `input` once and solves every part with `params`, failing with
+    /// the first violated assumption instead of a wrong answer.
+    fn solve(input: &str, params: &Params) -> Result<Vec<Answer>> {
+        let parsed = Self::parse(input)?;
+        if let Some(violation) = Self::validate(input, params).into_iter().next() {
+            return Err(violation);
+        }
+        let mut answers = vec![Self::part_1(&parsed, params)?.into()];
+        if Self::PARTS > 1 {
+            answers.push(Self::part_2(&parsed, params)?.into());
+        }
+        Ok(answers)
+    }
+}
+
+/// Parsed input of a [`Day`], opaque to everything but the day itself.
+pub struct Parsed(Box<dyn Any + Send + Sync>);
+
+/// A registry entry: a [`Solution`] with its types erased.
+pub struct Day {
+    pub year: &'static str,
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: u8,
+    pub params: &'static [&'static str],
+    parse: fn(&str) -> Result<Parsed>,
+    validate: fn(&str, &Params) -> Vec<Error>,
+    generate: fn(u64, usize) -> Option<String>,
+    solve: fn(&Parsed, u8, &Params) -> Result<Answer>,
+    reference: fn(&Parsed, u8, &Params) -> Option<Result<Answer>>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>(year: &'static str) -> Day {
+        Day {
+            year,
+            day: S::DAY,
+            title: S::TITLE,
+            parts: S::PARTS,
+            params: S::PARAMS,
+            parse: parse_erased::<S>,
+            validate: S::validate,
+            generate: S::generate,
+            solve: solve_erased::<S>,
+            reference: reference_erased::<S>,
+        }
+    }
+
+    /// The zero-padded day used in input file names.
+    pub fn name(&self) -> String {
+        format!("{:02}", self.day)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        (self.parse)(input)
+    }
+
+    /// The violated assumptions of the solver, for an input that parses.
+    pub fn validate(&self, input: &str, params: &Params) -> Vec<Error> {
+        (self.validate)(input, params)
+    }
+
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        (self.generate)(seed, size)
+    }
+
+    pub fn solve(&self, parsed: &Parsed, part: u8, params: &Params) -> Result<Answer> {
+        assert!(
+            (1..=self.parts).contains(&part),
+            "Day {} of {} has no part {part}",
+            self.day,
+            self.year
+        );
+        (self.solve)(parsed, part, params)
+    }
+
+    /// The answer of the part's reference solver, if it has one for `parsed`.
+    pub fn reference(&self, parsed: &Parsed, part: u8, params: &Params) -> Option<Result<Answer>> {
+        (self.reference)(parsed, part, params)
+    }
+
+    pub fn input_path(&self, variant: Option<&str>) -> PathBuf {
+        input::path(self.year, &self.name(), variant)
+    }
+
+    pub fn read_input(&self, variant: Option<&str>) -> Result<String, input::InputError> {
+        input::read(self.year, &self.name(), variant)
+    }
+}
+
+/// A year crate's days along with the answers bundled with it.
+pub struct Year {
+    pub year: &'static str,
+    pub days: &'static [Day],
+    pub answers: &'static str,
+}
+
+impl Year {
+    pub fn get_day(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<Parsed> {
+    Ok(Parsed(Box::new(S::parse(input)?)))
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> &S::Input {
+    parsed
+        .0
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input belongs to another day")
+}
+
+fn solve_erased<S: Solution>(parsed: &Parsed, part: u8, params: &Params) -> Result<Answer> {
+    let input = downcast::<S>(parsed);
+    match part {
+        1 => S::part_1(input, params).map(Into::into),
+        _ => S::part_2(input, params).map(Into::into),
+    }
+}
+
+fn reference_erased<S: Solution>(
+    parsed: &Parsed,
+    part: u8,
+    params: &Params,
+) -> Option<Result<Answer>> {
+    let input = downcast::<S>(parsed);
+    match part {
+        1 => S::reference_1(input, params).map(|answer| answer.map(Into::into)),
+        _ => S::reference_2(input, params).map(|answer| answer.map(Into::into)),
+    }
+}
+
+/// Solves the input named by `line`, a line of the answers file, with its
+/// parameters and asserts its answer. The tests generated from each year's
+/// answers file call this, one line each.
+pub fn check_entry(year: &Year, line: &str) {
+    let answers = Answers::parse(line, "test").unwrap_or_else(|err| panic!("{err}"));
+    for entry in answers.entries() {
+        let day = year
+            .get_day(entry.day)
+            .unwrap_or_else(|| panic!("{} has no day {}", year.year, entry.day));
+        let variant = (entry.variant != answers::INPUT_VARIANT).then_some(entry.variant.as_str());
+        let text = day
+            .read_input(variant)
+            .unwrap_or_else(|err| panic!("{err}"));
+        let answer = day
+            .parse(&text)
+            .and_then(
+                |parsed| match day.validate(&text, &entry.params).into_iter().next() {
+                    Some(violation) => Err(violation),
+                    None => day.solve(&parsed, entry.part, &entry.params),
+                },
+            )
+            .unwrap_or_else(|err| panic!("{:#}", err.in_file(&day.input_path(variant))));
+        assert_eq!(
+            answer.to_string(),
+            entry.answer,
+            "{} day {:02} part {} ({} {})",
+            year.year,
+            entry.day,
+            entry.part,
+            entry.variant,
+            entry.params
+        );
+    }
+}
+
+/// Entry point of the per-day binaries: solves the input file given as the
+/// argument, stdin for `-`, or by default the real input, with the default
+/// parameters. Solver events are shown according to `AOC_LOG`.
+pub fn main<S: Solution>(year: &str) {
+    input::init_from_args();
+    log::init_from_env();
+    let source = input::source_from_args(env::args().skip(1));
+    if let Err(err) = solve_input::<S>(year, source.as_deref()) {
+        eprintln!("error: {err:#}");
+        process::exit(1);
+    }
+}
+
+fn solve_input<S: Solution>(year: &str, source: Option<&str>) -> Result<()> {
+    let (text, path) = match source {
+        Some(source) => input::read_source(source)?,
+        None => {
+            let day = format!("{:02}", S::DAY);
+            (
+                input::read(year, &day, None)?,
+                input::path(year, &day, None),
+            )
+        }
+    };
+    let answers = S::solve(&text, &Params::new()).map_err(|err| err.in_file(&path))?;
+    for (k, answer) in answers.iter().enumerate() {
+        println!("Answer for part {}: {answer}", k + 1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-7_isize).to_string(), "-7");
+        assert_eq!(Answer::from("6,1").to_string(), "6,1");
+    }
+
+    #[test]
+    fn params_fall_back_to_default() {
+        let params = Params::new().with("width", 11);
+        assert_eq!(params.get("width", 101_isize), Ok(11));
+        assert_eq!(params.get("height", 103_isize), Ok(103));
+        assert_eq!(
+            Params::new()
+                .with("n_fallen", -1)
+                .get("n_fallen", 1024_usize)
+                .unwrap_err()
+                .to_string(),
+            "parameter --n-fallen out of range: -1"
+        );
+    }
+
+    struct Words;
+
+    impl Solution for Words {
+        const DAY: u8 = 1;
+        const PARTS: u8 = 1;
+        type Input = Vec<String>;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn parse(input: &str) -> Result<Vec<String>> {
+            Ok(input.split_whitespace().map(str::to_string).collect())
+        }
+
+        fn part_1(input: &Vec<String>, _params: &Params) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part_2(_input: &Vec<String>, _params: &Params) -> Result<usize> {
+            unreachable!("one part only")
+        }
+    }
+
+    #[test]
+    fn solve_text() {
+        let answers = Words::solve("in memory text", &Params::new()).unwrap();
+        assert_eq!(answers, [Answer::Unsigned(3)]);
+    }
+}
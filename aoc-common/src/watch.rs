@@ -0,0 +1,185 @@
+//! Polling a day's files for changes, for the `watch` command, and rebuilding
+//! the running binary so that a changed solver takes effect.
+//!
+//! Changes are found by comparing modification times, so nothing beyond the
+//! standard library is needed.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::time::{Duration, SystemTime};
+
+use crate::alloc;
+use crate::scaffold::Layout;
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The files that decide a day's answers: its module, the answers file, and
+/// its real and example inputs.
+pub fn day_files(layout: &Layout, day: u8) -> Vec<PathBuf> {
+    let mut files = vec![layout.module(day), layout.answers.clone()];
+    let prefix = format!("{day:02}.input");
+    let mut inputs = fs::read_dir(layout.input_root.join(&layout.year))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .collect::<Vec<_>>();
+    inputs.sort();
+    files.extend(inputs);
+    files
+}
+
+/// The modification times of a set of files, `None` for those missing.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(files: &[PathBuf]) -> Snapshot {
+        Snapshot(
+            files
+                .iter()
+                .map(|path| {
+                    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+                    (path.clone(), modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// The files added, removed or modified since `earlier`.
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let mut changed = self
+            .0
+            .iter()
+            .filter(|&(path, modified)| earlier.0.get(path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.extend(
+            earlier
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .cloned(),
+        );
+        changed
+    }
+}
+
+/// Rebuilds the binary at `exe` with cargo, in the profile it was built in
+/// and with the features of the running one.
+pub fn rebuild(exe: &Path, layout: &Layout) -> io::Result<ExitStatus> {
+    rebuild_command(exe, layout)?.status()
+}
+
+/// The cargo command behind [`rebuild`]. Binaries of the year crate are built
+/// from that package, as only the dispatcher is a default member of the
+/// workspace.
+fn rebuild_command(exe: &Path, layout: &Layout) -> io::Result<Command> {
+    let name = exe
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| io::Error::other("the running executable has no name"))?;
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    cargo
+        .current_dir(&layout.workspace)
+        .args(["build", "--quiet", "--bin", name]);
+    let package = layout.crate_dir.file_name().and_then(|dir| dir.to_str());
+    let src = layout.crate_dir.join("src");
+    if let Some(package) = package
+        && (name == package || src.join("bin").join(format!("{name}.rs")).is_file())
+    {
+        cargo.args(["-p", package]);
+    }
+    match exe
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|dir| dir.to_str())
+    {
+        Some("debug") | None => {}
+        Some(profile) => {
+            cargo.args(["--profile", profile]);
+        }
+    }
+    if alloc::enabled() {
+        cargo.args(["--features", "count-allocations"]);
+    }
+    Ok(cargo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    /// Runs the commands against this workspace, so the binaries are built
+    /// if they are not already.
+    #[test]
+    fn rebuild_builds_every_kind_of_binary() {
+        let layout = Layout::for_year("2024").unwrap();
+        let target = layout.workspace.join("target").join("debug");
+        for name in ["advent-of-code-rust", "aoc2024", "05"] {
+            let exe = target.join(format!("{name}{}", env::consts::EXE_SUFFIX));
+            let status = rebuild_command(&exe, &layout).unwrap().status().unwrap();
+            assert!(status.success(), "building {name}");
+        }
+    }
+
+    #[test]
+    fn snapshots_see_modified_added_and_removed_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("input/2024")).unwrap();
+        let layout = Layout {
+            year: "2024".to_string(),
+            workspace: dir.clone(),
+            crate_dir: dir.join("aoc2024"),
+            template: dir.join("template_rs"),
+            input_root: dir.join("input"),
+            answers: dir.join("input/2024/answers.txt"),
+        };
+        for name in ["06.input", "06.input.example", "07.input"] {
+            fs::write(dir.join("input/2024").join(name), "").unwrap();
+        }
+
+        let files = day_files(&layout, 6);
+        assert_eq!(
+            files,
+            [
+                dir.join("aoc2024/src/day06.rs"),
+                dir.join("input/2024/answers.txt"),
+                dir.join("input/2024/06.input"),
+                dir.join("input/2024/06.input.example"),
+            ]
+        );
+        let before = Snapshot::take(&files);
+        assert_eq!(before.changed_since(&before), Vec::<PathBuf>::new());
+
+        let input = dir.join("input/2024/06.input");
+        File::options()
+            .write(true)
+            .open(&input)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        fs::remove_file(dir.join("input/2024/06.input.example")).unwrap();
+        fs::write(dir.join("input/2024/06.input.example2"), "").unwrap();
+        let after = Snapshot::take(&day_files(&layout, 6));
+        assert_eq!(
+            after.changed_since(&before),
+            [
+                input,
+                dir.join("input/2024/06.input.example2"),
+                dir.join("input/2024/06.input.example"),
+            ]
+        );
+    }
+}
@@ -1,114 +1,5 @@
-// use std::env;
-use std::collections::HashMap;
-use std::fs;
-use std::path::Path;
-
-static YEAR: &str = "2024";
-static DAY: &str = "01";
-
-fn load_data(prefix: Option<&str>, suffix: Option<&str>) -> (Vec<usize>, Vec<usize>) {
-    let mut file_name = format!("input/{YEAR}/{DAY}.input");
-    match prefix {
-        None => (),
-        Some(p) => file_name = format!("{p}{file_name}"),
-    }
-    match suffix {
-        None => (),
-        Some(s) => file_name = format!("{file_name}{s}"),
-    }
-
-    let input =
-        fs::read_to_string(Path::new(&file_name)).expect("Should have been able to read the file");
-
-    let mut vec1: Vec<usize> = Vec::new();
-    let mut vec2: Vec<usize> = Vec::new();
-
-    for line in input.split("\n") {
-        if line.len() > 0 {
-            let split_line = line.split("   ").collect::<Vec<_>>();
-            if split_line.len() == 2 {
-                vec1.push(
-                    split_line[0]
-                        .parse::<usize>()
-                        .expect("Expect numerical value"),
-                );
-                vec2.push(
-                    split_line[1]
-                        .parse::<usize>()
-                        .expect("Expect numerical value"),
-                );
-            }
-        }
-    }
-
-    (vec1, vec2)
-}
-
-fn solve_part_1(prefix: Option<&str>, suffix: Option<&str>) -> usize {
-    let (mut vec1, mut vec2) = load_data(prefix, suffix);
-    vec1.sort();
-    vec2.sort();
-
-    let mut result: usize = 0;
-    for (val1, val2) in vec1.iter().zip(vec2.iter()) {
-        if val1 > val2 {
-            result += val1 - val2
-        } else {
-            result += val2 - val1
-        }
-    }
-
-    result
-}
-
-fn solve_part_2(prefix: Option<&str>, suffix: Option<&str>) -> usize {
-    let (vec1, vec2) = load_data(prefix, suffix);
-
-    let mut count = HashMap::new();
-    for val in vec2.iter() {
-        *count.entry(val).or_insert(0) += 1;
-    }
-
-    let mut result: usize = 0;
-    for val in vec1.iter() {
-        result += val * count.get(val).unwrap_or(&0);
-    }
-    result
-}
+use aoc2024::day01::Day01;
 
 fn main() {
-    let answer1 = solve_part_1(None, None);
-    println!("Answer for part 1: {answer1}");
-
-    let answer2 = solve_part_2(None, None);
-    println!("Answer for part 2: {answer2}");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example_part_1() {
-        let result = solve_part_1(Some("../"), Some(".example"));
-        assert_eq!(result, 11);
-    }
-
-    #[test]
-    fn answer_part_1() {
-        let result = solve_part_1(Some("../"), None);
-        assert_eq!(result, 2970687);
-    }
-
-    #[test]
-    fn example_part_2() {
-        let result = solve_part_2(Some("../"), Some(".example"));
-        assert_eq!(result, 31);
-    }
-
-    #[test]
-    fn answer_part_2() {
-        let result = solve_part_2(Some("../"), None);
-        assert_eq!(result, 23963899);
-    }
+    aoc2024::solution::main::<Day01>();
 }
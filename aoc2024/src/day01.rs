@@ -0,0 +1,85 @@
+// use std::env;
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::error::{Result, fail_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<usize>, Vec<usize>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut vec1: Vec<usize> = Vec::new();
+        let mut vec2: Vec<usize> = Vec::new();
+
+        for line in parse::lines(input) {
+            let [left, right] = parse::words(input, line)?[..] else {
+                return fail_at(input, line, "expected two numbers");
+            };
+            vec1.push(left);
+            vec2.push(right);
+        }
+
+        Ok((vec1, vec2))
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let left = (0..size)
+            .map(|_| generate::number(&mut rng, 5))
+            .collect::<Vec<_>>();
+        let mut rows = Vec::new();
+        for l in &left {
+            // Repeat some left numbers on the right for the similarity score.
+            let right = match rng.random_bool(0.3) {
+                true => *left.choose(&mut rng).unwrap(),
+                false => generate::number(&mut rng, 5),
+            };
+            rows.push(format!("{l}   {right}"));
+        }
+        Some(generate::lines(rows))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (mut vec1, mut vec2) = input.clone();
+        vec1.sort();
+        vec2.sort();
+
+        let mut result: usize = 0;
+        for (val1, val2) in vec1.iter().zip(vec2.iter()) {
+            if val1 > val2 {
+                result += val1 - val2
+            } else {
+                result += val2 - val1
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (vec1, vec2) = input;
+
+        let mut count = HashMap::new();
+        for val in vec2.iter() {
+            *count.entry(val).or_insert(0) += 1;
+        }
+
+        let mut result: usize = 0;
+        for val in vec1.iter() {
+            result += val * count.get(val).unwrap_or(&0);
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,94 @@
+use rand::Rng;
+
+use crate::error::Result;
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+fn is_report_safe(report: Vec<isize>, tolerance: i8) -> bool {
+    if tolerance < 0 {
+        return false;
+    }
+    if report.len() < 2 {
+        return true;
+    }
+
+    let mut latest_value = report[0];
+    let direction: isize = if report[1] > report[0] { 1 } else { -1 };
+    for (k, value) in report.iter().skip(1).enumerate() {
+        let change = (value - latest_value) * direction;
+        if change > 3 || change <= 0 {
+            let mut option1 = report.clone();
+            option1.remove(k);
+            let mut option2 = report.clone();
+            option2.remove(k + 1);
+            let mut option3 = report.clone();
+            option3.remove(0);
+
+            return is_report_safe(option1, tolerance - 1)
+                || is_report_safe(option2, tolerance - 1)
+                || is_report_safe(option3, tolerance - 1);
+        }
+        latest_value = *value;
+    }
+
+    true
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<isize>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+            .map(|line| parse::words(input, line))
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let reports = (0..size).map(|_| {
+            let len = rng.random_range(5..=8);
+            let direction = if rng.random_bool(0.5) { 1 } else { -1 };
+            let mut levels = vec![rng.random_range(30..=70)];
+            for _ in 1..len {
+                levels.push(levels[levels.len() - 1] + direction * rng.random_range(1..=3));
+            }
+            // Break most reports, some beyond what the dampener tolerates.
+            for _ in 0..rng.random_range(0..=2) {
+                levels[rng.random_range(0..len)] = rng.random_range(1..=99);
+            }
+            generate::join(levels, " ")
+        });
+        Some(generate::lines(reports))
+    }
+
+    fn part_1(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+
+        for report in data.iter() {
+            if is_report_safe(report.to_vec(), 0) {
+                result += 1;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+        for report in data.iter() {
+            if is_report_safe(report.to_vec(), 1) {
+                result += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,117 @@
+use rand::Rng;
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::solution::{Params, Solution};
+
+enum Instruction {
+    Disable,
+    Enable,
+    Mult(usize, usize),
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.split("\n").map(|s| s.to_string()).collect())
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let junk = "!@#$%^&*[]{}<>?,' +-_:;/~()mulodnt"
+            .chars()
+            .collect::<Vec<_>>();
+        let mut memory = String::new();
+        for k in 0..size {
+            if k > 0 && k % 50 == 0 {
+                memory.push('\n');
+            }
+            let len = rng.random_range(0..8);
+            memory.push_str(&generate::word(&mut rng, &junk, len));
+            let (a, b) = (rng.random_range(0..1000), rng.random_range(0..1000));
+            let instruction = match rng.random_range(0..10) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                // Near misses that must not count.
+                2 => format!("mul({a},{b}]"),
+                3 => format!("mul[{a},{b})"),
+                4 => format!("mul({},{b})", a + 1000),
+                _ => format!("mul({a},{b})"),
+            };
+            memory.push_str(&instruction);
+        }
+        memory.push('\n');
+        Some(memory)
+    }
+
+    fn part_1(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+        let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+        for line in data.iter() {
+            let factors: Vec<(usize, usize)> = re
+                .captures_iter(line)
+                .map(|caps| {
+                    let (_, [a, b]) = caps.extract();
+                    (
+                        a.parse::<usize>().expect("Expect numerical value"),
+                        b.parse::<usize>().expect("Expect numerical value"),
+                    )
+                })
+                .collect();
+            for (a, b) in factors.iter() {
+                result += a * b;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+        let re = Regex::new(r"(mul\(\d{1,3},\d{1,3}\))|(do\(\)|don\'t\(\))").unwrap();
+        let re_mult = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+        let mut instructions: Vec<Instruction> = Vec::new();
+        for line in data.iter() {
+            for cap in re.captures_iter(line) {
+                let (_, [a]) = cap.extract();
+                match a {
+                    "do()" => instructions.push(Instruction::Enable),
+                    "don't()" => instructions.push(Instruction::Disable),
+                    _ => {
+                        let Some(cap_mult) = re_mult.captures(a) else {
+                            return Err(Error::new(format!("unknown instruction {a:?}")));
+                        };
+                        let (_, [b, c]) = cap_mult.extract();
+                        instructions.push(Instruction::Mult(
+                            b.parse::<usize>().expect("Expect numerical value"),
+                            c.parse::<usize>().expect("Expect numerical value"),
+                        ))
+                    }
+                }
+            }
+        }
+
+        let mut enabled = true;
+        for instruction in instructions.iter() {
+            match instruction {
+                Instruction::Enable => enabled = true,
+                Instruction::Disable => enabled = false,
+                Instruction::Mult(a, b) => {
+                    if enabled {
+                        result += a * b;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
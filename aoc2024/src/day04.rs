@@ -0,0 +1,83 @@
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::error::Result;
+use crate::generate;
+use crate::grid::{ALL_DIRECTIONS, Grid};
+use crate::solution::{Params, Solution};
+
+fn count_word(line: impl Iterator<Item = char>, word: &str) -> usize {
+    let line: String = line.collect();
+    let reversed: String = word.chars().rev().collect();
+    (0..line.len())
+        .filter(|k| line[*k..].starts_with(word) || line[*k..].starts_with(&reversed))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, Some)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let size = size.max(4);
+        let cells = (0..size * size)
+            .map(|_| *['X', 'M', 'A', 'S'].choose(&mut rng).unwrap())
+            .collect();
+        let mut grid = Grid::from_cells(size, cells);
+        for _ in 0..size * size / 16 {
+            let start = (rng.random_range(0..size), rng.random_range(0..size));
+            let direction = *ALL_DIRECTIONS.choose(&mut rng).unwrap();
+            let word = grid.ray(start, direction).take(4).collect::<Vec<_>>();
+            if word.len() == 4 {
+                for (pos, letter) in word.into_iter().zip("XMAS".chars()) {
+                    grid[pos] = letter;
+                }
+            }
+        }
+        Some(generate::grid(&grid))
+    }
+
+    fn part_1(grid: &Self::Input, _params: &Params) -> Result<usize> {
+        let rows = grid
+            .rows()
+            .map(|row| count_word(row.iter().copied(), "XMAS"));
+        let columns = grid.columns().map(|c| count_word(c.copied(), "XMAS"));
+        let diagonals = grid.diagonals().map(|d| count_word(d.copied(), "XMAS"));
+        let anti_diagonals = grid
+            .anti_diagonals()
+            .map(|d| count_word(d.copied(), "XMAS"));
+
+        Ok(rows
+            .chain(columns)
+            .chain(diagonals)
+            .chain(anti_diagonals)
+            .sum())
+    }
+
+    fn part_2(grid: &Self::Input, _params: &Params) -> Result<usize> {
+        let corner = |pos, k: usize| grid.step(pos, ALL_DIRECTIONS[k]).map(|p| grid[p]);
+        let count = grid
+            .find_all(&'A')
+            .filter(|pos| {
+                // Corners clockwise from the top right, so opposite corners
+                // are two apart.
+                let [ne, se, sw, nw] = [1, 3, 5, 7].map(|k| corner(*pos, k));
+                let is_mas =
+                    |a, b| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+                is_mas(nw, se) && is_mas(ne, sw)
+            })
+            .count();
+        Ok(count)
+    }
+}
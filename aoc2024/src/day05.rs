@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom};
+
+use crate::error::{Error, Result, parse_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+fn validate_page(page: &[u8], rules: &[(u8, u8)]) -> bool {
+    for (first, second) in rules.iter() {
+        if page.contains(first) && page.contains(second) {
+            let index1 = page.iter().position(|&x| x == *first).unwrap();
+            let index2 = page.iter().position(|&x| x == *second).unwrap();
+            if index1 > index2 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// The update in the order of the rules, found by taking, again and again,
+/// the one page that no other remaining page must come before. `None` when
+/// the rules leave the order open or go round in a circle.
+fn order_by_rules(update: &[u8], rules: &HashSet<(u8, u8)>) -> Option<Vec<u8>> {
+    let mut remaining = update.to_vec();
+    let mut ordered = Vec::new();
+    while !remaining.is_empty() {
+        let first = remaining
+            .iter()
+            .filter(|&&page| {
+                !remaining
+                    .iter()
+                    .any(|&other| rules.contains(&(other, page)))
+            })
+            .collect::<Vec<_>>();
+        let [&first] = first[..] else {
+            return None;
+        };
+        remaining.retain(|&page| page != first);
+        ordered.push(first);
+    }
+    Some(ordered)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (Vec<(u8, u8)>, Vec<Vec<u8>>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rule_text, page_text) = parse::two_blocks(input, input)?;
+
+        let mut rules: Vec<(u8, u8)> = Vec::new();
+        for rule in parse::lines(rule_text) {
+            let (first, second) = parse::split_once(input, rule, "|", "a rule like 47|53")?;
+            rules.push((parse_at(input, first)?, parse_at(input, second)?));
+        }
+
+        let page_lists = parse::lines(page_text)
+            .map(|page| parse::list(input, page, ","))
+            .collect::<Result<_>>()?;
+
+        Ok((rules, page_lists))
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let mut pages = (10..100_u8).collect::<Vec<_>>();
+        pages.shuffle(&mut rng);
+        pages.truncate(49);
+        // Every pair of pages has a rule, so that each update has one order.
+        let mut rules = Vec::new();
+        for (k, first) in pages.iter().enumerate() {
+            for second in &pages[k + 1..] {
+                rules.push(format!("{first}|{second}"));
+            }
+        }
+        rules.shuffle(&mut rng);
+        let updates = (0..size).map(|_| {
+            let len = 2 * rng.random_range(2..=11) + 1;
+            let mut update = pages.choose_multiple(&mut rng, len).collect::<Vec<_>>();
+            update.shuffle(&mut rng);
+            if rng.random_bool(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|p| p == *page));
+            }
+            generate::join(update, ",")
+        });
+        Some(format!(
+            "{}\n{}",
+            generate::lines(rules),
+            generate::lines(updates)
+        ))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (rules, page_lists) = input;
+
+        let result: usize = page_lists
+            .iter()
+            .filter(|&page| validate_page(page, rules))
+            .map(|page| usize::from(page[page.len() / 2]))
+            .sum();
+
+        Ok(result)
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (rules, page_lists) = input;
+
+        let ordering: HashSet<(u8, u8)> = rules.iter().copied().collect();
+
+        let mut result = 0;
+        for page in page_lists
+            .iter()
+            .filter(|&page| !validate_page(page, rules))
+        {
+            let fixed = order_by_rules(page, &ordering)
+                .ok_or_else(|| Error::new("the rules do not put an update in order"))?;
+            result += usize::from(fixed[fixed.len() / 2]);
+        }
+
+        Ok(result)
+    }
+
+    fn reference_2(input: &Self::Input, _params: &Params) -> Option<Result<usize>> {
+        let (rules, page_lists) = input;
+        let mut result = 0;
+        for update in page_lists {
+            // Under a total order, a page goes after exactly the pages the
+            // rules put before it.
+            let mut ordered = vec![None; update.len()];
+            for &page in update {
+                let before = update
+                    .iter()
+                    .filter(|&&other| rules.contains(&(other, page)))
+                    .count();
+                if ordered.get_mut(before)?.replace(page).is_some() {
+                    return None;
+                }
+            }
+            let ordered = ordered.into_iter().collect::<Option<Vec<_>>>()?;
+            if ordered != *update {
+                result += usize::from(ordered[ordered.len() / 2]);
+            }
+        }
+        Some(Ok(result))
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::Rng;
+
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::solution::{Params, Solution};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Node {
+    point: Point,
+    facing: Direction,
+}
+
+#[derive(Clone)]
+enum NextNode {
+    Moved(Node),
+    Turned(Node),
+    Exited,
+}
+
+#[derive(Clone)]
+pub struct NodeMap {
+    width: isize,
+    height: isize,
+    next_nodes: HashMap<Node, NextNode>,
+}
+
+impl NodeMap {
+    fn get_next_node(&self, node: &Node) -> Option<&NextNode> {
+        self.next_nodes.get(node)
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y)
+    }
+
+    fn new(width: usize, height: usize) -> NodeMap {
+        let mut node_map = NodeMap {
+            width: width as isize,
+            height: height as isize,
+            next_nodes: HashMap::new(),
+        };
+        for y in 0..node_map.height {
+            for x in 0..node_map.width {
+                let point = Point::new(x, y);
+                for facing in Direction::ALL {
+                    let next = point.step(facing);
+                    let next_node = if node_map.contains(next) {
+                        NextNode::Moved(Node {
+                            point: next,
+                            facing,
+                        })
+                    } else {
+                        NextNode::Exited
+                    };
+                    node_map
+                        .next_nodes
+                        .insert(Node { point, facing }, next_node);
+                }
+            }
+        }
+        node_map
+    }
+
+    /// Makes every node walking into `point` turn right instead.
+    fn add_obstacle(&mut self, point: Point) {
+        for facing in Direction::ALL {
+            let before = point - facing.delta();
+            if self.contains(before) {
+                self.next_nodes.insert(
+                    Node {
+                        point: before,
+                        facing,
+                    },
+                    NextNode::Turned(Node {
+                        point: before,
+                        facing: facing.turn_right(),
+                    }),
+                );
+            }
+        }
+    }
+
+    fn remove_obstacle(&mut self, point: Point) {
+        for facing in Direction::ALL {
+            let before = point - facing.delta();
+            if self.contains(before) {
+                self.next_nodes.insert(
+                    Node {
+                        point: before,
+                        facing,
+                    },
+                    NextNode::Moved(Node { point, facing }),
+                );
+            }
+        }
+    }
+}
+
+enum PathResult {
+    Exited(Vec<Node>),
+    Loop,
+}
+
+fn get_guard_path(map: &NodeMap, mut path: Vec<Node>) -> PathResult {
+    let mut node = path.last().expect("Path must not be empty").clone();
+    let mut previous_nodes = HashSet::new();
+    previous_nodes.insert(node.clone());
+    loop {
+        match map.get_next_node(&node).expect("No next node found.") {
+            NextNode::Exited => return PathResult::Exited(path),
+            NextNode::Moved(n) => node = n.clone(),
+            NextNode::Turned(n) => node = n.clone(),
+        }
+        if previous_nodes.contains(&node) {
+            return PathResult::Loop;
+        }
+        path.push(node.clone());
+        previous_nodes.insert(node.clone());
+    }
+}
+
+fn get_guard_exit_path(map: &NodeMap, node: Node) -> Result<Vec<Node>> {
+    match get_guard_path(map, vec![node]) {
+        PathResult::Exited(path) => Ok(path),
+        PathResult::Loop => Err(Error::new("the guard never leaves the map")),
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = (NodeMap, Node);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input, |c| {
+            (matches!(c, '.' | '#') || Direction::from_arrow(c).is_some()).then_some(c)
+        })?;
+        let mut node_map = NodeMap::new(grid.width(), grid.height());
+        let mut starting_node = None;
+        for (pos, c) in grid.iter() {
+            if *c == '#' {
+                node_map.add_obstacle(pos.into());
+            } else if let Some(facing) = Direction::from_arrow(*c) {
+                starting_node = Some(Node {
+                    point: pos.into(),
+                    facing,
+                });
+            }
+        }
+
+        let starting_node = starting_node.ok_or_else(|| Error::new("no guard on the map"))?;
+        Ok((node_map, starting_node))
+    }
+
+    fn validate(input: &str, _params: &Params) -> Vec<Error> {
+        input
+            .matches(|c| Direction::from_arrow(c).is_some())
+            .skip(1)
+            .map(|guard| Error::new("more than one guard on the map").at(input, guard))
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let size = size.max(2);
+        // Redraw until the guard walks off the map, as part 1 needs.
+        loop {
+            let mut grid = Grid::new(size, size, '.');
+            for pos in grid.positions() {
+                if rng.random_bool(0.05) {
+                    grid[pos] = '#';
+                }
+            }
+            grid[(rng.random_range(0..size), rng.random_range(0..size))] = '^';
+            let input = generate::grid(&grid);
+            let (map, guard) = Self::parse(&input).ok()?;
+            if get_guard_exit_path(&map, guard).is_ok() {
+                return Some(input);
+            }
+        }
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (map, node) = input;
+        let visited: HashSet<Point> = get_guard_exit_path(map, node.clone())?
+            .into_iter()
+            .map(|n| n.point)
+            .collect();
+
+        Ok(visited.len())
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+        let (mut map, node) = input.clone();
+        let original_path = get_guard_exit_path(&map, node.clone())?;
+
+        let mut tested = HashSet::new();
+        tested.insert(node.point);
+        let mut path = vec![node.clone()];
+        for node in original_path.iter().skip(1) {
+            if tested.contains(&node.point) {
+                continue;
+            }
+            map.add_obstacle(node.point);
+            if let PathResult::Loop = get_guard_path(&map, path.clone()) {
+                result += 1
+            }
+            map.remove_obstacle(node.point);
+            path.push(node.clone());
+            tested.insert(node.point);
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,132 @@
+use rand::Rng;
+
+use crate::error::{Result, parse_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+use crate::trace;
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+fn validate(target: usize, values: &[usize], operations: Vec<Operation>) -> bool {
+    if values.len() == 1 {
+        if values[0] == target {
+            return true;
+        }
+    } else if values.len() > 1 {
+        for operation in operations.clone().iter() {
+            let value = values.last().unwrap();
+            trace!("validating", target, values, operation);
+            let new_target = match operation {
+                Operation::Add => {
+                    if *value > target {
+                        continue;
+                    }
+                    target - value
+                }
+                Operation::Multiply => {
+                    if !target.is_multiple_of(*value) {
+                        continue;
+                    }
+                    target / value
+                }
+                Operation::Concatenate => {
+                    let next_power_of_10 = 10_usize.pow(value.ilog10() + 1);
+                    if (*value > target) || !(target - *value).is_multiple_of(next_power_of_10) {
+                        continue;
+                    }
+                    (target - value) / next_power_of_10
+                }
+            };
+            if validate(new_target, &values[..values.len() - 1], operations.clone()) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut data = Vec::new();
+        for line in parse::lines(input) {
+            let (target, values) =
+                parse::split_once(input, line, ": ", "an equation like 190: 10 19")?;
+            data.push((parse_at(input, target)?, parse::words(input, values)?));
+        }
+
+        Ok(data)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let mut equations = Vec::new();
+        while equations.len() < size {
+            let values = (0..rng.random_range(2..=9))
+                .map(|_| {
+                    let digits = rng.random_range(1..=3);
+                    generate::number(&mut rng, digits)
+                })
+                .collect::<Vec<_>>();
+            // Plant a solution, with concatenation in only some of them.
+            let concatenate = rng.random_bool(0.5);
+            let mut target = Some(values[0]);
+            for &value in &values[1..] {
+                target = target.and_then(|target| match rng.random_range(0..3) {
+                    0 => target.checked_add(value),
+                    1 => target.checked_mul(value),
+                    _ if concatenate => format!("{target}{value}").parse().ok(),
+                    _ => target.checked_add(value),
+                });
+            }
+            // Too large for the solver, or knocked off by one to make it
+            // likely unsolvable.
+            let Some(target) = target.filter(|&target| target < 1 << 60) else {
+                continue;
+            };
+            let target = target + u64::from(rng.random_bool(0.3));
+            equations.push(format!("{target}: {}", generate::join(values, " ")));
+        }
+        Some(generate::lines(equations))
+    }
+
+    fn part_1(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+        for (target, values) in data.iter() {
+            if validate(*target, values, vec![Operation::Add, Operation::Multiply]) {
+                result += target;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut result: usize = 0;
+        for (target, values) in data.iter() {
+            if validate(
+                *target,
+                values,
+                vec![Operation::Add, Operation::Multiply, Operation::Concatenate],
+            ) {
+                result += target;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,93 @@
+use itertools::iproduct;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::Result;
+use crate::generate;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::solution::{Params, Solution};
+
+pub struct AntennaMap {
+    antennas: HashMap<char, Vec<Point>>,
+    grid: Grid<char>,
+}
+
+fn get_nodes(map: &AntennaMap, start: isize, limit: Option<isize>) -> HashSet<Point> {
+    let mut nodes = HashSet::new();
+    for locations in map.antennas.values() {
+        for (location1, location2) in iproduct!(locations, locations) {
+            if location1 == location2 {
+                continue;
+            }
+            let direction = *location2 - *location1;
+            let mut step = start;
+            loop {
+                if let Some(l) = limit
+                    && step > l
+                {
+                    break;
+                }
+                let next = *location2 + direction * step;
+                if map.grid.at(next).is_none() {
+                    break;
+                }
+                nodes.insert(next);
+                step += 1;
+            }
+        }
+    }
+
+    nodes
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = AntennaMap;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input, Some)?;
+        let mut antennas = HashMap::new();
+        for (pos, c) in grid.iter().filter(|(_, c)| **c != '.') {
+            antennas
+                .entry(*c)
+                .or_insert_with(Vec::new)
+                .push(Point::from(pos));
+        }
+
+        Ok(AntennaMap { antennas, grid })
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let size = size.max(1);
+        let frequencies = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .collect::<Vec<_>>();
+        let mut grid = Grid::new(size, size, '.');
+        for &frequency in frequencies.iter().take((size / 2).max(1)) {
+            for _ in 0..rng.random_range(2..=4) {
+                let pos = (rng.random_range(0..size), rng.random_range(0..size));
+                if grid[pos] == '.' {
+                    grid[pos] = frequency;
+                }
+            }
+        }
+        Some(generate::grid(&grid))
+    }
+
+    fn part_1(map: &Self::Input, _params: &Params) -> Result<usize> {
+        Ok(get_nodes(map, 1, Some(1)).len())
+    }
+
+    fn part_2(map: &Self::Input, _params: &Params) -> Result<usize> {
+        Ok(get_nodes(map, 0, None).len())
+    }
+}
@@ -0,0 +1,223 @@
+use rand::Rng;
+
+use crate::error::{Error, Result, fail_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BlockType {
+    Empty,
+    File,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Block {
+    number: Option<usize>,
+    size: usize,
+    block_type: BlockType,
+}
+
+impl Block {
+    fn new_empty(size: usize) -> Block {
+        Block {
+            number: None,
+            size,
+            block_type: BlockType::Empty,
+        }
+    }
+
+    fn new_file(number: usize, size: usize) -> Block {
+        Block {
+            number: Some(number),
+            size,
+            block_type: BlockType::File,
+        }
+    }
+}
+
+fn get_checksum(blocks: Vec<Block>) -> usize {
+    let mut checksum: usize = 0;
+
+    let mut k: usize = 0;
+    for block in blocks {
+        match block.block_type {
+            BlockType::Empty => (),
+            BlockType::File => {
+                let number = block.number.unwrap();
+                for l in k..k + block.size {
+                    checksum += number * l;
+                }
+            }
+        }
+        k += block.size;
+    }
+
+    checksum
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Vec<Block>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut blocks = Vec::new();
+        let line = input.split("\n").next().unwrap_or_default();
+        for (k, char) in line.char_indices() {
+            let Some(size) = char.to_digit(10) else {
+                return fail_at(input, &line[k..k + char.len_utf8()], "expected a digit");
+            };
+            if k % 2 == 0 {
+                blocks.push(Block::new_file(k / 2, size as usize));
+            } else if size > 0 {
+                blocks.push(Block::new_empty(size as usize));
+            }
+        }
+
+        if blocks.is_empty() {
+            return Err(Error::new("empty disk map"));
+        }
+        Ok(blocks)
+    }
+
+    fn validate(input: &str, _params: &Params) -> Vec<Error> {
+        let mut lines = parse::lines(input);
+        let mut violations = Vec::new();
+        if let Some(line) = lines.next() {
+            let empty_files = line
+                .match_indices('0')
+                .filter(|(k, _)| k % 2 == 0)
+                .map(|(_, size)| Error::new("file of size 0").at(input, size));
+            violations.extend(empty_files);
+        }
+        if let Some(line) = lines.next() {
+            violations
+                .push(Error::new("only the first line of the disk map is read").at(input, line));
+        }
+        violations
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        // Files alternate with free space, starting and ending with a file.
+        let map = (0..size.max(1) | 1)
+            .map(|k| {
+                let low = if k % 2 == 0 { 1 } else { 0 };
+                char::from(b'0' + rng.random_range(low..=9))
+            })
+            .collect::<String>();
+        Some(format!("{map}\n"))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut blocks = input.clone();
+
+        loop {
+            let mut last_block = blocks.pop().unwrap();
+            if last_block.block_type == BlockType::Empty {
+                continue;
+            }
+            let mut finished = false;
+            loop {
+                match blocks.iter().position(|b| b.block_type == BlockType::Empty) {
+                    None => {
+                        blocks.push(last_block);
+                        finished = true;
+                        break;
+                    }
+                    Some(i) => {
+                        let first_empty_index = i;
+                        let first_empty_size = blocks[first_empty_index].size;
+                        blocks.remove(first_empty_index);
+                        if first_empty_size >= last_block.size {
+                            blocks.insert(first_empty_index, last_block);
+                            if first_empty_size > last_block.size {
+                                blocks.insert(
+                                    first_empty_index + 1,
+                                    Block::new_empty(first_empty_size - last_block.size),
+                                );
+                            }
+                            break;
+                        } else {
+                            blocks.insert(
+                                first_empty_index,
+                                Block::new_file(last_block.number.unwrap(), first_empty_size),
+                            );
+                            last_block.size -= first_empty_size;
+                        }
+                    }
+                }
+            }
+            if finished {
+                break;
+            }
+        }
+
+        Ok(get_checksum(blocks))
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut blocks = input.clone();
+
+        let max_number = blocks
+            .iter()
+            .rev()
+            .find(|b| b.block_type == BlockType::File)
+            .unwrap()
+            .number
+            .unwrap();
+
+        for number in (1..max_number + 1).rev() {
+            let block_index = blocks
+                .iter()
+                .position(|b| b.block_type == BlockType::File && b.number.unwrap() == number)
+                .unwrap();
+            let block_size = blocks[block_index].size;
+            let mut empty_block_index_and_size: Option<(usize, usize)> = None;
+            for (k, empty_block) in blocks.iter().enumerate().skip(1) {
+                if k == block_index {
+                    break;
+                }
+                if empty_block.block_type == BlockType::File {
+                    continue;
+                }
+                if empty_block.size >= block_size {
+                    empty_block_index_and_size = Some((k, empty_block.size));
+                    break;
+                }
+            }
+            match empty_block_index_and_size {
+                None => (),
+                Some((k, empty_block_size)) => {
+                    blocks.remove(k);
+                    blocks.insert(k, Block::new_file(number, block_size));
+                    blocks.remove(block_index);
+                    if block_index >= blocks.len() {
+                        blocks.push(Block::new_empty(block_size));
+                    } else {
+                        if blocks[block_index].block_type == BlockType::File {
+                            blocks.insert(block_index, Block::new_empty(block_size));
+                        } else {
+                            blocks[block_index].size += block_size;
+                        }
+                    }
+                    if empty_block_size > block_size {
+                        if blocks[k + 1].block_type == BlockType::File {
+                            blocks.insert(k + 1, Block::new_empty(empty_block_size - block_size));
+                        } else {
+                            blocks[k + 1].size += empty_block_size - block_size;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(get_checksum(blocks))
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::error::Result;
+use crate::generate;
+use crate::grid::{Grid, Pos};
+use crate::solution::{Params, Solution};
+
+fn score_peaks(map: &Grid<u8>, start: Pos) -> HashMap<Pos, u32> {
+    let mut peaks = HashMap::new();
+    let elevation = map[start];
+    if elevation == 9 {
+        peaks.insert(start, 1);
+        return peaks;
+    }
+
+    for next in map.neighbours(start) {
+        if map[next] == elevation + 1 {
+            for (peak, score) in score_peaks(map, next) {
+                *peaks.entry(peak).or_insert(0) += score;
+            }
+        }
+    }
+
+    peaks
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let size = size.max(1);
+        let mut map = Grid::new(size, size, 0);
+        for pos in map.positions() {
+            map[pos] = rng.random_range(0..=9);
+        }
+        // Random digits rarely climb, so lay trails from 0 to 9 over them.
+        for _ in 0..size * size / 10 {
+            let mut pos = (rng.random_range(0..size), rng.random_range(0..size));
+            for height in 0..=9 {
+                map[pos] = height;
+                let next = map.neighbours(pos).collect::<Vec<_>>();
+                pos = *next.choose(&mut rng).unwrap_or(&pos);
+            }
+        }
+        Some(generate::grid(&map.map(|height| char::from(b'0' + height))))
+    }
+
+    fn part_1(map: &Self::Input, _params: &Params) -> Result<usize> {
+        Ok(map
+            .find_all(&0)
+            .map(|trailhead| score_peaks(map, trailhead).len())
+            .sum::<usize>())
+    }
+
+    fn part_2(map: &Self::Input, _params: &Params) -> Result<u32> {
+        Ok(map
+            .find_all(&0)
+            .map(|trailhead| score_peaks(map, trailhead).values().sum::<u32>())
+            .sum::<u32>())
+    }
+}
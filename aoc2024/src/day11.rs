@@ -0,0 +1,76 @@
+use rand::Rng;
+
+use crate::error::Result;
+use crate::generate;
+use crate::memo::Memo;
+use crate::parse;
+use crate::solution::{Params, Solution};
+use crate::trace;
+
+fn score(stone: usize, n_blinks: u8, memo: &mut Memo<(usize, u8), usize>) -> usize {
+    if n_blinks == 0 {
+        return 1;
+    }
+    if let Some(&score) = memo.get(&(stone, n_blinks)) {
+        return score;
+    }
+
+    trace!("scoring stone", stone, n_blinks);
+    let stone_digits = if stone > 0 { stone.ilog10() + 1 } else { 1 };
+    let new_stones = if stone == 0 {
+        vec![1]
+    } else if stone_digits % 2 == 0 {
+        vec![
+            stone % 10_usize.pow(stone_digits / 2),
+            stone / 10_usize.pow(stone_digits / 2),
+        ]
+    } else {
+        vec![stone * 2024]
+    };
+
+    let total = new_stones
+        .iter()
+        .map(|new_stone| score(*new_stone, n_blinks - 1, memo))
+        .sum();
+    memo.insert((stone, n_blinks), total)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::words(input, input)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let stones = (0..size.max(1)).map(|_| {
+            let digits = rng.random_range(1..=7);
+            generate::number(&mut rng, digits)
+        });
+        Some(format!("{}\n", generate::join(stones, " ")))
+    }
+
+    fn part_1(stones: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut memo = Memo::new("stones");
+        Ok(stones
+            .iter()
+            .map(|stone| score(*stone, 25, &mut memo))
+            .sum())
+    }
+
+    fn part_2(stones: &Self::Input, _params: &Params) -> Result<usize> {
+        let mut memo = Memo::new("stones");
+        Ok(stones
+            .iter()
+            .map(|stone| score(*stone, 75, &mut memo))
+            .sum())
+    }
+}
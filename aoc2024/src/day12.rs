@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use crate::error::Result;
+use crate::generate;
+use crate::grid::{Grid, ORTHOGONAL, Pos};
+use crate::solution::{Params, Solution};
+
+fn find_region(map: &Grid<char>, start: Pos) -> HashSet<Pos> {
+    let mut region = HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(pos) = stack.pop() {
+        for next in map.neighbours(pos) {
+            if map[next] == map[start] && region.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    region
+}
+
+fn find_all_regions(map: &Grid<char>) -> Vec<HashSet<Pos>> {
+    let mut assigned = Grid::new(map.width(), map.height(), false);
+    let mut regions = Vec::new();
+    for pos in map.positions() {
+        if assigned[pos] {
+            continue;
+        }
+        let region = find_region(map, pos);
+        for p in region.iter() {
+            assigned[*p] = true;
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+fn is_fence(map: &Grid<char>, region: &HashSet<Pos>, pos: Pos, delta: (isize, isize)) -> bool {
+    map.step(pos, delta).is_none_or(|n| !region.contains(&n))
+}
+
+fn get_perimeter(map: &Grid<char>, region: &HashSet<Pos>) -> usize {
+    region
+        .iter()
+        .map(|pos| {
+            ORTHOGONAL
+                .into_iter()
+                .filter(|d| is_fence(map, region, *pos, *d))
+                .count()
+        })
+        .sum()
+}
+
+fn get_perimeter_sections(map: &Grid<char>, region: &HashSet<Pos>) -> usize {
+    // (side, line, offset along the line) of every fence segment.
+    let mut fence_sections = Vec::new();
+    for &(x, y) in region.iter() {
+        for (k, (dx, dy)) in ORTHOGONAL.into_iter().enumerate() {
+            if !is_fence(map, region, (x, y), (dx, dy)) {
+                continue;
+            }
+            let (x_new, y_new) = (x as isize + dx, y as isize + dy);
+            // Fences above and below run along x, those left and right along y.
+            fence_sections.push(if dx == 0 {
+                (k, y_new, x_new)
+            } else {
+                (k, x_new, y_new)
+            });
+        }
+    }
+
+    fence_sections.sort();
+
+    1 + fence_sections
+        .windows(2)
+        .filter(|w| (w[0].0, w[0].1, w[0].2 + 1) != w[1])
+        .count()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, Some)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let size = size.max(1);
+        let mut map = Grid::new(size, size, 'A');
+        // Mostly copy a neighbour's plant, which grows regions of all shapes.
+        for (x, y) in map.positions() {
+            map[(x, y)] = match rng.random_range(0..10) {
+                0..4 if x > 0 => map[(x - 1, y)],
+                4..8 if y > 0 => map[(x, y - 1)],
+                _ => char::from(rng.random_range(b'A'..=b'Z')),
+            };
+        }
+        Some(generate::grid(&map))
+    }
+
+    fn part_1(map: &Self::Input, _params: &Params) -> Result<usize> {
+        let regions = find_all_regions(map);
+        Ok(regions
+            .iter()
+            .map(|region| get_perimeter(map, region) * region.len())
+            .sum())
+    }
+
+    fn part_2(map: &Self::Input, _params: &Params) -> Result<usize> {
+        let regions = find_all_regions(map);
+        Ok(regions
+            .iter()
+            .map(|region| get_perimeter_sections(map, region) * region.len())
+            .sum())
+    }
+}
@@ -0,0 +1,102 @@
+use rand::Rng;
+
+use crate::error::{Error, Result, fail_at};
+use crate::generate;
+use crate::geometry::Point;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+#[derive(Copy, Clone)]
+pub struct Machine {
+    a: Point<i64>,
+    b: Point<i64>,
+    p: Point<i64>,
+}
+
+fn find_machine_cost(machine: Machine) -> Result<i64> {
+    let det = machine.a.x * machine.b.y - machine.a.y * machine.b.x;
+    if det == 0 {
+        return Err(Error::new("buttons A and B move in the same direction"));
+    }
+    let d_a = machine.b.y * machine.p.x - machine.b.x * machine.p.y;
+    let d_b = machine.a.x * machine.p.y - machine.a.y * machine.p.x;
+    if d_a % det == 0 && d_b % det == 0 {
+        let n_a = d_a / det;
+        let n_b = d_b / det;
+        if n_a > 0 && n_b > 0 {
+            return Ok(3 * n_a + n_b);
+        }
+    }
+    Ok(0)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Vec<Machine>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::blocks(input)
+            .map(|block| {
+                let [ax, ay, bx, by, px, py] = parse::integers(input, block)?[..] else {
+                    return fail_at(input, block, "expected two buttons and a prize");
+                };
+                Ok(Machine {
+                    a: Point::new(ax, ay),
+                    b: Point::new(bx, by),
+                    p: Point::new(px, py),
+                })
+            })
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let mut machines = Vec::new();
+        while machines.len() < size {
+            let mut button = || Point::new(rng.random_range(10..100), rng.random_range(10..100));
+            let (a, b) = (button(), button());
+            if a.x * b.y == a.y * b.x {
+                continue;
+            }
+            // Plant a number of presses for half of the machines.
+            let prize = match rng.random_bool(0.5) {
+                true => a * rng.random_range(1..=100) + b * rng.random_range(1..=100),
+                false => Point::new(rng.random_range(1000..20000), rng.random_range(1000..20000)),
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.x, a.y, b.x, b.y, prize.x, prize.y
+            ));
+        }
+        Some(generate::join(machines, "\n"))
+    }
+
+    fn part_1(machines: &Self::Input, _params: &Params) -> Result<i64> {
+        machines
+            .iter()
+            .map(|machine| find_machine_cost(*machine))
+            .sum()
+    }
+
+    fn part_2(machines: &Self::Input, _params: &Params) -> Result<i64> {
+        machines
+            .iter()
+            .map(|machine| {
+                find_machine_cost(Machine {
+                    a: machine.a,
+                    b: machine.b,
+                    p: Point {
+                        x: machine.p.x + 10_000_000_000_000,
+                        y: machine.p.y + 10_000_000_000_000,
+                    },
+                })
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,208 @@
+use std::ops::Range;
+
+use rand::Rng;
+
+use crate::debug;
+use crate::error::{Error, Result, fail_at};
+use crate::generate;
+use crate::geometry::Point;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    fn move_robot(&mut self, dt: isize, width: isize, height: isize) {
+        let moved = self.position + self.velocity * dt;
+        self.position = Point::new(moved.x.rem_euclid(width), moved.y.rem_euclid(height));
+    }
+}
+
+fn safety_factor(robots: &[Robot], width: isize, height: isize) -> usize {
+    let sectors = [
+        robots
+            .iter()
+            .filter(|robot| robot.position.x < width / 2 && robot.position.y < height / 2)
+            .count(),
+        robots
+            .iter()
+            .filter(|robot| robot.position.x > width / 2 && robot.position.y < height / 2)
+            .count(),
+        robots
+            .iter()
+            .filter(|robot| robot.position.x < width / 2 && robot.position.y > height / 2)
+            .count(),
+        robots
+            .iter()
+            .filter(|robot| robot.position.x > width / 2 && robot.position.y > height / 2)
+            .count(),
+    ];
+
+    debug!(
+        "quadrant counts",
+        middle_x = width / 2,
+        middle_y = height / 2,
+        sectors
+    );
+    sectors.iter().product()
+}
+
+fn variance(robots: &[Robot]) -> (f32, f32) {
+    let mean_x: f32 = robots
+        .iter()
+        .map(|robot| robot.position.x as f32)
+        .sum::<f32>()
+        / robots.len() as f32;
+    let mean_y: f32 = robots
+        .iter()
+        .map(|robot| robot.position.y as f32)
+        .sum::<f32>()
+        / robots.len() as f32;
+
+    let var_x = robots
+        .iter()
+        .map(|robot| (robot.position.x as f32 - mean_x).powi(2))
+        .sum::<f32>()
+        / robots.len() as f32;
+    let var_y = robots
+        .iter()
+        .map(|robot| (robot.position.y as f32 - mean_y).powi(2))
+        .sum::<f32>()
+        / robots.len() as f32;
+    (var_x.sqrt(), var_y.sqrt())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+    const PARAMS: &'static [&'static str] = &["width", "height"];
+
+    type Input = Vec<Robot>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+            .map(|line| {
+                let [px, py, vx, vy] = parse::integers(input, line)?[..] else {
+                    return fail_at(input, line, "expected a robot like p=0,4 v=3,-3");
+                };
+                Ok(Robot {
+                    position: Point::new(px, py),
+                    velocity: Point::new(vx, vy),
+                })
+            })
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let (width, height) = (101, 103);
+        let mut point = |x: Range<i64>, y: Range<i64>| {
+            Point::new(rng.random_range(x) as isize, rng.random_range(y) as isize)
+        };
+        // Plant a picture: most robots end up packed in a square at time
+        // `at`, and are wound back from there.
+        let at = point(1..width * height, 0..1).x;
+        let corner = point(0..width - 20, 0..height - 20);
+        let robots = (0..size).map(|k| {
+            let velocity = point(-99..100, -99..100);
+            let position = match k % 3 {
+                0 => point(0..width, 0..height),
+                _ => corner + point(0..20, 0..20),
+            };
+            let mut robot = Robot { position, velocity };
+            if k % 3 != 0 {
+                robot.move_robot(-at, width as isize, height as isize);
+            }
+            format!(
+                "p={},{} v={},{}",
+                robot.position.x, robot.position.y, velocity.x, velocity.y
+            )
+        });
+        Some(generate::lines(robots))
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Result<usize> {
+        let width = params.get("width", 101)?;
+        let height = params.get("height", 103)?;
+        let mut robots = input.clone();
+        for robot in robots.iter_mut() {
+            robot.move_robot(100, width, height);
+        }
+
+        Ok(safety_factor(&robots, width, height))
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Result<usize> {
+        let width = params.get("width", 101)?;
+        let height = params.get("height", 103)?;
+        let mut robots = input.clone();
+        let mut variances_x = vec![];
+        let mut variances_y = vec![];
+
+        let max_dim = [width, height].into_iter().max().unwrap();
+        for _ in 0..max_dim {
+            for robot in robots.iter_mut() {
+                robot.move_robot(1, width, height);
+            }
+            let (var_x, var_y) = variance(&robots);
+            variances_x.push(var_x);
+            variances_y.push(var_y);
+        }
+
+        let min_var_x = variances_x.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+        let min_var_y = variances_y.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+
+        let min_var_x_index = variances_x.iter().position(|&x| x == min_var_x).unwrap() + 1;
+        let min_var_y_index = variances_y.iter().position(|&y| y == min_var_y).unwrap() + 1;
+        debug!(
+            "tightest clusters",
+            x_step = min_var_x_index,
+            y_step = min_var_y_index
+        );
+
+        let x_steps = (0..max_dim)
+            .map(|k| min_var_x_index + k as usize * width as usize)
+            .collect::<Vec<usize>>();
+        let mut result = min_var_y_index;
+        for _ in 0..max_dim {
+            if x_steps.contains(&result) {
+                return Ok(result);
+            }
+            result += height as usize;
+        }
+
+        Err(Error::new("the robots never line up"))
+    }
+
+    fn reference_2(input: &Self::Input, params: &Params) -> Option<Result<usize>> {
+        let (width, height) = match (params.get("width", 101), params.get("height", 103)) {
+            (Ok(width), Ok(height)) => (width, height),
+            (Err(err), _) | (_, Err(err)) => return Some(Err(err)),
+        };
+        // The robots are back where they started every width * height
+        // seconds, so try every second until then for the one at which they
+        // are packed the tightest: the least variance of their positions over
+        // both axes, in exact integers scaled by the squared count.
+        let n = input.len() as isize;
+        let spread = |seconds: isize| {
+            let (mut sum, mut squares) = (Point::new(0, 0), 0);
+            for robot in input {
+                let mut robot = *robot;
+                robot.move_robot(seconds, width, height);
+                sum += robot.position;
+                squares += robot.position.x.pow(2) + robot.position.y.pow(2);
+            }
+            n * squares - sum.x.pow(2) - sum.y.pow(2)
+        };
+        let tightest = (1..=width * height).min_by_key(|&seconds| spread(seconds))?;
+        Some(Ok(tightest as usize))
+    }
+}
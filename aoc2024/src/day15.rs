@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+struct Map {
+    current: Point,
+    boxes: HashSet<Point>,
+    walls: Grid<bool>,
+    doubled: bool,
+}
+
+impl Map {
+    fn is_wall(&self, p: Point) -> bool {
+        self.walls.at(p).copied().unwrap_or(true)
+    }
+
+    fn move_robot(&mut self, direction: Direction) {
+        if self.doubled {
+            self.move_robot_double(direction);
+        } else {
+            self.move_robot_single(direction);
+        }
+    }
+
+    fn move_robot_single(&mut self, direction: Direction) {
+        let mut empty_space_found = false;
+        let mut p = self.current;
+        loop {
+            p = p.step(direction);
+            if self.is_wall(p) {
+                break;
+            } else if self.boxes.contains(&p) {
+                continue;
+            }
+            empty_space_found = true;
+            break;
+        }
+        if empty_space_found {
+            self.current = self.current.step(direction);
+            self.boxes.insert(p);
+            self.boxes.remove(&self.current);
+        }
+    }
+
+    fn move_robot_double(&mut self, direction: Direction) {
+        let delta = direction.delta();
+        let left = Direction::Left.delta();
+        let right = Direction::Right.delta();
+
+        let mut boxes = HashSet::new();
+        let mut to_check = HashSet::from([self.current + delta]);
+        let mut empty_space_found = false;
+        loop {
+            if to_check.iter().any(|p| self.is_wall(*p)) {
+                break;
+            }
+            // Boxes are stored by their left half.
+            let mut new_boxes = HashSet::new();
+            for p in to_check.iter() {
+                if self.boxes.contains(p) {
+                    new_boxes.insert(*p);
+                } else if self.boxes.contains(&(*p + left)) {
+                    new_boxes.insert(*p + left);
+                }
+            }
+            if !new_boxes.is_empty() {
+                let mut new_to_check = HashSet::new();
+                match direction {
+                    Direction::Right => {
+                        new_to_check.extend(new_boxes.iter().map(|p| *p + right * 2));
+                    }
+                    Direction::Left => new_to_check.extend(new_boxes.iter().map(|p| *p + left)),
+                    Direction::Up | Direction::Down => {
+                        for p in new_boxes.iter() {
+                            new_to_check.insert(*p + delta);
+                            new_to_check.insert(*p + delta + right);
+                        }
+                    }
+                }
+                boxes.extend(new_boxes);
+                to_check = new_to_check;
+                continue;
+            }
+            empty_space_found = true;
+            break;
+        }
+        if empty_space_found {
+            self.current += delta;
+            for b in boxes.iter() {
+                self.boxes.remove(b);
+            }
+            for b in boxes.iter() {
+                self.boxes.insert(*b + delta);
+            }
+        }
+    }
+
+    fn sum_gps(&self) -> usize {
+        self.boxes
+            .iter()
+            .fold(0, |acc, p| acc + p.x as usize + 100 * p.y as usize)
+    }
+}
+
+fn build_map(warehouse: &Grid<char>, doubled: bool) -> Map {
+    let scale = if doubled { 2 } else { 1 };
+    let mut map = Map {
+        current: Point::new(0, 0),
+        boxes: HashSet::new(),
+        walls: Grid::new(scale * warehouse.width(), warehouse.height(), false),
+        doubled,
+    };
+
+    for ((x, y), c) in warehouse.iter() {
+        let point = Point::from((scale * x, y));
+        match c {
+            '#' => {
+                for dx in 0..scale {
+                    map.walls[(scale * x + dx, y)] = true;
+                }
+            }
+            '@' => map.current = point,
+            'O' => {
+                map.boxes.insert(point);
+            }
+            _ => {}
+        }
+    }
+
+    map
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+
+    type Input = (Grid<char>, Vec<Direction>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (warehouse, moves) = parse::two_blocks(input, input)?;
+        let directions = moves.chars().filter_map(Direction::from_arrow).collect();
+        let warehouse = Grid::parse(warehouse, |c| "#.O@".contains(c).then_some(c))
+            .map_err(|err| err.within(input, warehouse))?;
+        if warehouse.find(&'@').is_none() {
+            return Err(Error::new("no robot in the warehouse"));
+        }
+
+        Ok((warehouse, directions))
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let size = size.max(3);
+        let mut warehouse = Grid::new(size, size, '.');
+        for (x, y) in warehouse.positions() {
+            warehouse[(x, y)] = if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+                '#'
+            } else {
+                match rng.random_range(0..20) {
+                    0 => '#',
+                    1..5 => 'O',
+                    _ => '.',
+                }
+            };
+        }
+        warehouse[(rng.random_range(1..size - 1), rng.random_range(1..size - 1))] = '@';
+        let moves = (0..4 * size * size)
+            .map(|_| *['^', '>', 'v', '<'].choose(&mut rng).unwrap())
+            .collect::<Vec<_>>();
+        let moves = moves.chunks(70).map(|line| line.iter().collect::<String>());
+        Some(format!(
+            "{}\n{}",
+            generate::grid(&warehouse),
+            generate::lines(moves)
+        ))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (warehouse, directions) = input;
+        let mut map = build_map(warehouse, false);
+        for direction in directions {
+            map.move_robot(*direction);
+        }
+        Ok(map.sum_gps())
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (warehouse, directions) = input;
+        let mut map = build_map(warehouse, true);
+        for direction in directions {
+            map.move_robot(*direction);
+        }
+        Ok(map.sum_gps())
+    }
+}
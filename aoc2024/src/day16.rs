@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use crate::debug;
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::search::{self, Search};
+use crate::solution::{Params, Solution};
+
+pub struct Maze {
+    walls: Grid<bool>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    /// Anything outside the grid counts as a wall.
+    fn is_wall(&self, point: &Point) -> bool {
+        self.walls.at(*point).copied().unwrap_or(true)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct RouteStep {
+    point: Point,
+    facing: Direction,
+}
+
+fn find_best_routes(maze: &Maze) -> Search<RouteStep> {
+    let start = RouteStep {
+        point: maze.start,
+        facing: Direction::Right,
+    };
+    let moves = |current: &RouteStep| {
+        let forward = RouteStep {
+            point: current.point.step(current.facing),
+            facing: current.facing,
+        };
+        let turns =
+            [current.facing.turn_left(), current.facing.turn_right()].map(|facing| RouteStep {
+                point: current.point,
+                facing,
+            });
+        (!maze.is_wall(&forward.point))
+            .then_some((forward, 1))
+            .into_iter()
+            .chain(turns.map(|turn| (turn, 1000)))
+    };
+
+    search::dijkstra(start, moves, |step| step.point == maze.end)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = Maze;
+    type Output1 = u64;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input, |c| "#.SE".contains(c).then_some(c))?;
+        let point = |c| {
+            let pos = grid
+                .find(&c)
+                .ok_or_else(|| Error::new(format!("no {c} in the maze")))?;
+            Ok::<_, Error>(Point::from(pos))
+        };
+
+        Ok(Maze {
+            start: point('S')?,
+            end: point('E')?,
+            walls: grid.map(|c| *c == '#'),
+        })
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let mut walls = generate::maze(&mut rng, size, size);
+        // Loops give the reindeer several routes, some of them tied.
+        generate::open_walls(&mut rng, &mut walls, size * size / 20);
+        let (width, height) = (walls.width(), walls.height());
+        let mut maze = walls.map(|wall| if *wall { '#' } else { '.' });
+        maze[(1, height - 2)] = 'S';
+        maze[(width - 2, 1)] = 'E';
+        Some(generate::grid(&maze))
+    }
+
+    fn part_1(maze: &Self::Input, _params: &Params) -> Result<u64> {
+        find_best_routes(maze)
+            .cost()
+            .ok_or_else(|| Error::new("no route through the maze"))
+    }
+
+    fn part_2(maze: &Self::Input, _params: &Params) -> Result<usize> {
+        let routes = find_best_routes(maze);
+        let visited: HashSet<Point> = routes
+            .on_optimal_paths()
+            .into_iter()
+            .map(|step| step.point)
+            .collect();
+        debug!("best routes", cost = routes.cost(), tiles = visited.len());
+
+        Ok(visited.len())
+    }
+}
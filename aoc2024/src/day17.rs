@@ -0,0 +1,274 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::error::{Error, Result, parse_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+#[derive(Clone)]
+pub struct Computer {
+    a: isize,
+    b: isize,
+    c: isize,
+    program: Vec<isize>,
+    pointer: usize,
+    ouput: Vec<isize>,
+}
+
+impl Computer {
+    fn new(program: Vec<isize>, a: isize) -> Computer {
+        Computer {
+            a,
+            b: 0,
+            c: 0,
+            program,
+            pointer: 0,
+            ouput: Vec::new(),
+        }
+    }
+
+    fn get_combo_operands(&self, operand: isize) -> Result<isize> {
+        if operand <= 3 {
+            return Ok(operand);
+        } else if operand == 4 {
+            return Ok(self.a);
+        } else if operand == 5 {
+            return Ok(self.b);
+        } else if operand == 6 {
+            return Ok(self.c);
+        }
+        Err(Error::new(format!(
+            "invalid combo operand {operand} at instruction {}",
+            self.pointer
+        )))
+    }
+
+    fn run(&mut self) -> Result<()> {
+        loop {
+            if self.pointer >= self.program.len() - 1 {
+                break;
+            }
+
+            let (op_code, operand) = (self.program[self.pointer], self.program[self.pointer + 1]);
+            match op_code {
+                0 => {
+                    self.a >>= self.get_combo_operands(operand)?;
+                }
+                1 => {
+                    self.b ^= operand;
+                }
+                2 => {
+                    self.b = self.get_combo_operands(operand)? % 8;
+                }
+                3 if self.a != 0 => {
+                    self.pointer = operand as usize;
+                    continue;
+                }
+                4 => {
+                    self.b ^= self.c;
+                }
+                5 => {
+                    self.ouput.push(self.get_combo_operands(operand)? % 8);
+                }
+                6 => {
+                    self.b = self.a >> self.get_combo_operands(operand)?;
+                }
+                7 => {
+                    self.c = self.a >> self.get_combo_operands(operand)?;
+                }
+                _ => (),
+            }
+            self.pointer += 2;
+        }
+        Ok(())
+    }
+}
+
+/// The longest program whose quine the reference searches for by trying every
+/// value of register A.
+const BRUTE_FORCE_LEN: usize = 6;
+
+/// Whether a program has the puzzle's shape: B and C worked out from the low
+/// bits of A, B output, and A shifted by 3 bits on each pass. C is taken
+/// while B is below 8, so each output only depends on the lowest 10 bits of
+/// A at the start of its pass.
+fn is_puzzle_shaped(program: &[isize]) -> bool {
+    let pairs = program.chunks(2).collect::<Vec<_>>();
+    let [[2, 4], [1, _], [7, 5], body @ .., [5, 5], [3, 0]] = &pairs[..] else {
+        return false;
+    };
+    body.iter().all(|pair| matches!(pair, [1 | 4, _] | [0, 3]))
+        && body.iter().filter(|pair| **pair == [0, 3]).count() == 1
+}
+
+/// The smallest quine of a puzzle-shaped program, built up from the lowest
+/// octal digit of A: the candidates for its lowest `k + 4` digits whose first
+/// `k + 1` outputs match the program, each extended by every next digit.
+fn lowest_quine(program: &[isize]) -> Result<Option<isize>> {
+    const WINDOW: u32 = 4;
+    let len = program.len();
+    let first_output = |a: isize| -> Result<isize> {
+        let mut computer = Computer::new(program.to_vec(), a & 0o1777);
+        computer.run()?;
+        Ok(computer.ouput[0])
+    };
+    let limit = 8_isize.pow(len as u32);
+    let mut candidates = (0..8_isize.pow(WINDOW).min(limit)).collect::<Vec<_>>();
+    for (k, &value) in program.iter().enumerate() {
+        let mut matching = Vec::new();
+        for a in candidates {
+            if first_output(a >> (3 * k))? == value {
+                matching.push(a);
+            }
+        }
+        let shift = 3 * (k as u32 + WINDOW);
+        candidates = matching
+            .into_iter()
+            .flat_map(|a| (0..8).map(move |digit| a | digit << shift))
+            .filter(|&a| a < limit)
+            .collect();
+    }
+    Ok(candidates.into_iter().filter(|&a| a >= limit / 8).min())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    type Input = Computer;
+    type Output1 = String;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let register_a = parse_at(input, parse::field(input, "Register A")?)?;
+        let program = parse::list(input, parse::field(input, "Program")?, ",")?;
+
+        Ok(Computer::new(program, register_a))
+    }
+
+    fn validate(input: &str, _params: &Params) -> Vec<Error> {
+        let mut violations = Vec::new();
+        for register in ["Register B", "Register C"] {
+            match parse::field(input, register) {
+                Ok("0") => {}
+                Ok(value) => violations.push(
+                    Error::new(format!("{register} is ignored and taken to be 0")).at(input, value),
+                ),
+                Err(err) => violations.push(err),
+            }
+        }
+        if let Ok(program) = parse::field(input, "Program") {
+            let values = program.split(',').collect::<Vec<_>>();
+            if values.len() % 2 == 1 {
+                violations
+                    .push(Error::new("the last instruction has no operand").at(input, program));
+            }
+            let out_of_range = values
+                .into_iter()
+                .filter(|value| !matches!(value.parse(), Ok(0..=7)))
+                .map(|value| Error::new("program values are 3-bit numbers").at(input, value));
+            violations.extend(out_of_range);
+        }
+        violations
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        // Programs shaped like the puzzle's: each loop outputs a few bits of
+        // B, worked out from the low bits of A, then shifts A by 3. Redraw
+        // until one has a quine for part 2. Small sizes may leave out the
+        // second xor on B or the one with C, for shorter programs.
+        loop {
+            let mut body = vec![[0, 3]];
+            for op in [1, 4] {
+                if size > BRUTE_FORCE_LEN || rng.random_bool(0.5) {
+                    body.push([op, rng.random_range(0..8)]);
+                }
+            }
+            body.shuffle(&mut rng);
+            let program = [[2, 4], [1, rng.random_range(0..8)], [7, 5]]
+                .into_iter()
+                .chain(body)
+                .chain([[5, 5], [3, 0]])
+                .flatten()
+                .collect::<Vec<_>>();
+            let digits = size.clamp(1, 16) as u32;
+            let a = rng.random_range(8_i64.pow(digits - 1)..8_i64.pow(digits));
+            let input = format!(
+                "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                generate::join(program, ",")
+            );
+            if Self::part_2(&Self::parse(&input).ok()?, &Params::new()).is_ok() {
+                return Some(input);
+            }
+        }
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<String> {
+        let mut computer = input.clone();
+        computer.run()?;
+        Ok(computer
+            .ouput
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<isize> {
+        let program = &input.program;
+        let mut solutions = vec![0];
+        let mut targets = vec![];
+        for k in 1..(program.len() + 1) {
+            targets.push(program[(program.len() - k)..].to_vec());
+        }
+
+        for target in targets {
+            let mut new_solutions = vec![];
+            for solution in solutions {
+                for a_ in 0..8 {
+                    let mut computer = Computer::new(program.clone(), 8 * solution + a_);
+                    computer.run()?;
+                    if computer.ouput == target {
+                        new_solutions.push(8 * solution + a_);
+                    }
+                }
+            }
+            solutions = new_solutions;
+        }
+
+        solutions
+            .into_iter()
+            .min()
+            .ok_or_else(|| Error::new("no value of register A outputs the program"))
+    }
+
+    fn reference_2(input: &Self::Input, _params: &Params) -> Option<Result<isize>> {
+        // A program that shifts A by 3 bits per output only outputs itself
+        // from a value below 8 to the power of its length.
+        let len = input.program.len();
+        if is_puzzle_shaped(&input.program) {
+            return Some(lowest_quine(&input.program).and_then(|a| {
+                a.ok_or_else(|| Error::new("no value of register A outputs the program"))
+            }));
+        }
+        if len > BRUTE_FORCE_LEN {
+            return None;
+        }
+        for a in 0..8_isize.pow(len as u32) {
+            let mut computer = Computer::new(input.program.clone(), a);
+            if let Err(err) = computer.run() {
+                return Some(Err(err));
+            }
+            if computer.ouput == input.program {
+                return Some(Ok(a));
+            }
+        }
+        Some(Err(Error::new(
+            "no value of register A outputs the program",
+        )))
+    }
+}
@@ -0,0 +1,165 @@
+use rand::seq::SliceRandom;
+
+use crate::error::{Error, Result, fail_at};
+use crate::generate;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::parse;
+use crate::search;
+use crate::solution::{Params, Solution};
+
+/// Number of steps from the top left to the bottom right corner.
+fn find_path(obstacles: &[Point]) -> Option<u64> {
+    let goal = Point {
+        x: obstacles.iter().map(|p| p.x).max().unwrap(),
+        y: obstacles.iter().map(|p| p.y).max().unwrap(),
+    };
+    let mut blocked = Grid::new(goal.x as usize + 1, goal.y as usize + 1, false);
+    for p in obstacles {
+        blocked[(p.x as usize, p.y as usize)] = true;
+    }
+
+    let free_neighbours = |current: &Point| {
+        current
+            .neighbours()
+            .filter(|n| blocked.at(*n).is_some_and(|b| !b))
+    };
+    search::bfs(Point::new(0, 0), free_neighbours, |p| *p == goal).cost()
+}
+
+/// The number of bytes that have fallen before part 1, which must be at least
+/// one for the memory space to have a size.
+fn fallen(obstacles: &[Point], params: &Params) -> Result<usize> {
+    let n_fallen = params.get("n_fallen", 1024)?;
+    if n_fallen == 0 || n_fallen > obstacles.len() {
+        return Err(Error::new(format!(
+            "{n_fallen} bytes cannot fall from a list of {}",
+            obstacles.len()
+        )));
+    }
+    Ok(n_fallen)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "RAM Run";
+    const PARAMS: &'static [&'static str] = &["n_fallen"];
+
+    type Input = Vec<Point>;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut obstacles = vec![];
+        for line in parse::lines(input) {
+            let [x, y] = parse::list(input, line, ",")?[..] else {
+                return fail_at(input, line, "expected a position like 5,4");
+            };
+            let point = Point::new(x, y);
+            if point.to_pos().is_none() {
+                return fail_at(input, line, "position outside the memory space");
+            }
+            obstacles.push(point)
+        }
+
+        Ok(obstacles)
+    }
+
+    fn validate(input: &str, params: &Params) -> Vec<Error> {
+        let Ok(obstacles) = Self::parse(input) else {
+            return Vec::new();
+        };
+        let lines = parse::lines(input).collect::<Vec<_>>();
+        let n_fallen = match fallen(&obstacles, params) {
+            Ok(n_fallen) => n_fallen,
+            Err(err) => return vec![err],
+        };
+
+        // The memory space is square, and its size is inferred from the
+        // bytes fallen for part 1.
+        let mut violations = Vec::new();
+        let size = |bytes: &[Point]| {
+            let x = bytes.iter().map(|p| p.x).max().unwrap();
+            let y = bytes.iter().map(|p| p.y).max().unwrap();
+            (x, y)
+        };
+        let (width, height) = size(&obstacles);
+        if width != height {
+            violations.push(Error::new(format!(
+                "the memory space is not square: the bytes reach x={width} and y={height}"
+            )));
+        }
+        if size(&obstacles[..n_fallen]) != (width, height) {
+            violations.push(Error::new(format!(
+                "the first {n_fallen} bytes do not reach the edges of the memory space"
+            )));
+        }
+        let corners = [Point::new(0, 0), Point::new(width, height)];
+        for (line, byte) in lines.iter().zip(&obstacles[..n_fallen]) {
+            if corners.contains(byte) {
+                violations
+                    .push(Error::new("a byte falls on the start or the exit").at(input, line));
+            }
+        }
+        violations
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        // At least the puzzle's memory space, which the 1024 bytes fallen in
+        // part 1 leave passable.
+        let side = size.max(71) as isize;
+        let (start, exit) = (Point::new(0, 0), Point::new(side - 1, side - 1));
+        loop {
+            let mut bytes = (0..side)
+                .flat_map(|y| (0..side).map(move |x| Point::new(x, y)))
+                .filter(|&p| p != start && p != exit)
+                .collect::<Vec<_>>();
+            bytes.shuffle(&mut rng);
+            bytes.truncate(bytes.len() * 7 / 10);
+            // The fallen bytes must reach the edges for the size to be
+            // inferred from them.
+            let right = bytes.iter().position(|p| p.x == exit.x)?;
+            bytes.swap(0, right);
+            let bottom = bytes[1..].iter().position(|p| p.y == exit.y)?;
+            bytes.swap(1, bottom + 1);
+            let input = generate::lines(bytes.iter().map(|p| format!("{},{}", p.x, p.y)));
+            let obstacles = Self::parse(&input).ok()?;
+            let params = Params::new();
+            if Self::part_1(&obstacles, &params).is_ok()
+                && Self::part_2(&obstacles, &params).is_ok()
+            {
+                return Some(input);
+            }
+        }
+    }
+
+    fn part_1(obstacles: &Self::Input, params: &Params) -> Result<usize> {
+        let n_fallen = fallen(obstacles, params)?;
+        let steps = find_path(&obstacles[..n_fallen]).ok_or_else(|| Error::new("no path found"))?;
+        Ok(steps as usize)
+    }
+
+    fn part_2(obstacles: &Self::Input, params: &Params) -> Result<String> {
+        let n_fallen = fallen(obstacles, params)?;
+
+        let mut min_n = n_fallen;
+        let mut max_n = obstacles.len() + 1;
+        let mut mid_n = 0;
+        while max_n - min_n > 1 {
+            mid_n = (min_n + max_n) / 2;
+            match find_path(&obstacles[..mid_n]) {
+                None => max_n = mid_n,
+                Some(_) => min_n = mid_n,
+            }
+        }
+
+        if max_n > obstacles.len() {
+            return Err(Error::new("the exit is never cut off"));
+        }
+        let first_blocker = obstacles[mid_n - 1];
+        Ok(format!("{},{}", first_blocker.x, first_blocker.y))
+    }
+}
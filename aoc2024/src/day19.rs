@@ -0,0 +1,140 @@
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::error::Result;
+use crate::generate;
+use crate::memo::Memo;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+/// Towels making up the design, if any. Both memo tables are keyed by what is
+/// left of the design, a slice of it.
+fn find_combination<'a>(
+    desired_pattern: &'a str,
+    available_patterns: &'a [String],
+    memo: &mut Memo<&'a str, Option<Vec<&'a str>>>,
+) -> Option<Vec<&'a str>> {
+    if let Some(combination) = memo.get(desired_pattern) {
+        return combination.clone();
+    }
+
+    let mut found = None;
+    for available in available_patterns.iter() {
+        if *available == desired_pattern {
+            found = Some(vec![available.as_str()]);
+            break;
+        }
+
+        if desired_pattern.starts_with(available.as_str())
+            && let Some(combination) = find_combination(
+                &desired_pattern[available.len()..],
+                available_patterns,
+                memo,
+            )
+        {
+            let mut new_combination = vec![available.as_str()];
+            new_combination.extend(combination);
+            found = Some(new_combination);
+            break;
+        }
+    }
+
+    memo.insert(desired_pattern, found)
+}
+
+fn count_all_combinations<'a>(
+    desired_pattern: &'a str,
+    available_patterns: &[String],
+    memo: &mut Memo<&'a str, usize>,
+) -> usize {
+    if let Some(&count) = memo.get(desired_pattern) {
+        return count;
+    }
+
+    let mut result: usize = 0;
+    for available in available_patterns.iter() {
+        if *available == desired_pattern {
+            result += 1;
+        }
+
+        if desired_pattern.starts_with(available.as_str()) {
+            result += count_all_combinations(
+                &desired_pattern[available.len()..],
+                available_patterns,
+                memo,
+            )
+        }
+    }
+
+    memo.insert(desired_pattern, result)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Linen Layout";
+
+    type Input = (Vec<String>, Vec<String>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (available, desired) = parse::two_blocks(input, input)?;
+        let available = available.split(", ").map(|s| s.to_string()).collect();
+        let desired = parse::lines(desired).map(|s| s.to_string()).collect();
+
+        Ok((available, desired))
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let colours = ['w', 'u', 'b', 'r', 'g'];
+        // Every colour but red on its own, so that not every design can be
+        // made but the number of arrangements stays in range.
+        let mut towels = ["w", "u", "b", "g"].map(String::from).to_vec();
+        for _ in 0..8 + size / 2 {
+            let len = rng.random_range(2..=8);
+            let towel = generate::word(&mut rng, &colours, len);
+            if !towels.contains(&towel) {
+                towels.push(towel);
+            }
+        }
+        let designs = (0..size)
+            .map(|_| match rng.random_bool(0.5) {
+                true => (0..rng.random_range(4..=10))
+                    .map(|_| towels.choose(&mut rng).unwrap().as_str())
+                    .collect::<String>(),
+                false => {
+                    let len = rng.random_range(20..=50);
+                    generate::word(&mut rng, &colours, len)
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(format!(
+            "{}\n\n{}",
+            towels.join(", "),
+            generate::lines(designs)
+        ))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (available, desired) = input;
+
+        let mut memo = Memo::new("designs");
+        Ok(desired
+            .iter()
+            .filter(|d| find_combination(d, available, &mut memo).is_some())
+            .collect::<Vec<_>>()
+            .len())
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (available, desired) = input;
+        let mut memo = Memo::new("arrangements");
+        Ok(desired
+            .iter()
+            .map(|d| count_all_combinations(d, available, &mut memo))
+            .sum())
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::geometry::{Direction, Point};
+use crate::grid::{Grid, Pos};
+use crate::search;
+use crate::solution::{Params, Solution};
+
+pub struct Maze {
+    walls: Grid<bool>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    /// Anything outside the grid counts as a wall.
+    fn is_wall(&self, point: &Point) -> bool {
+        self.walls.at(*point).copied().unwrap_or(true)
+    }
+}
+
+fn find_path(maze: &Maze) -> Vec<Point> {
+    let open_neighbours = |current: &Point| current.neighbours().filter(|next| !maze.is_wall(next));
+    search::bfs(maze.start, open_neighbours, |p| *p == maze.end)
+        .path()
+        .unwrap_or_default()
+}
+
+fn find_cheats(
+    maze: &Maze,
+    min_dt: isize,
+    max_dist_cheat: isize,
+) -> HashMap<(Point, Point), isize> {
+    let mut cheats = HashMap::new();
+    let mut path_map = HashMap::new();
+    for (t, coords) in find_path(maze).iter().enumerate() {
+        path_map.insert(*coords, t as isize);
+    }
+
+    for (coords, t) in path_map.iter() {
+        for main in Direction::ALL {
+            for secondary in [main.turn_left(), main.turn_right()] {
+                for k_main in 1..=max_dist_cheat {
+                    for k_secondary in 0..=(max_dist_cheat - k_main) {
+                        let cheat_coords =
+                            *coords + main.delta() * k_main + secondary.delta() * k_secondary;
+                        if let Some(t_cheat) = path_map.get(&cheat_coords) {
+                            let dist = cheat_coords.manhattan(*coords);
+                            let dt = t_cheat - t - dist;
+
+                            if dist <= max_dist_cheat && dt >= min_dt {
+                                cheats.insert((*coords, cheat_coords), dt);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    cheats
+}
+
+fn solve(maze: &Maze, min_dt: isize, max_dist_cheat: isize) -> usize {
+    let cheats = find_cheats(maze, min_dt, max_dist_cheat);
+
+    cheats.len()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Race Condition";
+    const PARAMS: &'static [&'static str] = &["min_dt", "max_dist_cheat"];
+
+    type Input = Maze;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input, |c| "#.SE".contains(c).then_some(c))?;
+        let point = |c| {
+            let pos = grid
+                .find(&c)
+                .ok_or_else(|| Error::new(format!("no {c} in the maze")))?;
+            Ok::<_, Error>(Point::from(pos))
+        };
+
+        Ok(Maze {
+            start: point('S')?,
+            end: point('E')?,
+            walls: grid.map(|c| *c == '#'),
+        })
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let walls = generate::maze(&mut rng, size, size);
+        // A single track: the path through the maze from the top left to
+        // the cell furthest from it.
+        let open = |pos: &Pos| {
+            walls
+                .neighbours(*pos)
+                .filter(|&n| !walls[n])
+                .collect::<Vec<_>>()
+        };
+        let start = (1, 1);
+        let reached = search::bfs(start, open, |_| false);
+        let end = walls
+            .positions()
+            .filter(|&p| !walls[p])
+            .max_by_key(|p| reached.cost_to(p))?;
+        let mut track = walls.map(|_| '#');
+        for pos in reached.path_to(&end)? {
+            track[pos] = '.';
+        }
+        track[start] = 'S';
+        track[end] = 'E';
+        Some(generate::grid(&track))
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Result<usize> {
+        Ok(solve(
+            input,
+            params.get("min_dt", 100)?,
+            params.get("max_dist_cheat", 2)?,
+        ))
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Result<usize> {
+        Ok(solve(
+            input,
+            params.get("min_dt", 100)?,
+            params.get("max_dist_cheat", 20)?,
+        ))
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, Result, fail_at, parse_at};
+use crate::generate;
+use crate::memo::Memo;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+fn get_numerical_direction_options(start: char, end: char) -> HashSet<String> {
+    let keys = HashMap::from([
+        ('0', (1, 3)),
+        ('1', (0, 2)),
+        ('2', (1, 2)),
+        ('3', (2, 2)),
+        ('4', (0, 1)),
+        ('5', (1, 1)),
+        ('6', (2, 1)),
+        ('7', (0, 0)),
+        ('8', (1, 0)),
+        ('9', (2, 0)),
+        ('A', (2, 3)),
+    ]);
+    let mut keys_locations = HashSet::new();
+    for (_, v) in keys.iter() {
+        keys_locations.insert(*v);
+    }
+
+    let (start_x, start_y) = keys.get(&start).unwrap();
+    let (end_x, end_y) = keys.get(&end).unwrap();
+
+    let dx: i32 = end_x - start_x;
+    let dy: i32 = end_y - start_y;
+    let x_operation = (if dx > 0 { ">" } else { "<" }).repeat(dx.unsigned_abs() as usize);
+    let y_operation = (if dy > 0 { "v" } else { "^" }).repeat(dy.unsigned_abs() as usize);
+
+    let mut options = HashSet::new();
+    if keys_locations.contains(&(start_x + dx, *start_y)) {
+        options.insert(format!("{x_operation}{y_operation}A"));
+    }
+    if keys_locations.contains(&(*start_x, start_y + dy)) {
+        options.insert(format!("{y_operation}{x_operation}A"));
+    }
+
+    options
+}
+
+fn get_directional_keypress_count(
+    start: char,
+    end: char,
+    n_robots: u8,
+    memo: &mut Memo<(char, char, u8), usize>,
+) -> usize {
+    if n_robots == 1 {
+        return 1;
+    }
+    if let Some(&count) = memo.get(&(start, end, n_robots)) {
+        return count;
+    }
+    let keys = HashMap::from([
+        ('^', (1, 0)),
+        ('v', (1, 1)),
+        ('<', (0, 1)),
+        ('>', (2, 1)),
+        ('A', (2, 0)),
+    ]);
+    let mut keys_locations = HashSet::new();
+    for (_, v) in keys.iter() {
+        keys_locations.insert(*v);
+    }
+
+    let (start_x, start_y) = keys.get(&start).unwrap();
+    let (end_x, end_y) = keys.get(&end).unwrap();
+
+    let dx: i32 = end_x - start_x;
+    let dy: i32 = end_y - start_y;
+    let x_operation = (if dx > 0 { ">" } else { "<" }).repeat(dx.unsigned_abs() as usize);
+    let y_operation = (if dy > 0 { "v" } else { "^" }).repeat(dy.unsigned_abs() as usize);
+
+    let mut options = HashSet::new();
+    if keys_locations.contains(&(start_x + dx, *start_y)) {
+        options.insert(format!("{x_operation}{y_operation}A"));
+    }
+    if keys_locations.contains(&(*start_x, start_y + dy)) {
+        options.insert(format!("{y_operation}{x_operation}A"));
+    }
+
+    let mut counts = vec![];
+    for option in options {
+        let mut count = 0;
+        for (start, end) in format!("A{}", option).chars().zip(option.chars()) {
+            count += get_directional_keypress_count(start, end, n_robots - 1, memo);
+        }
+        counts.push(count);
+    }
+
+    memo.insert((start, end, n_robots), *counts.iter().min().unwrap())
+}
+
+fn get_human_keypress_count(
+    code: &str,
+    n_robots: u8,
+    memo: &mut Memo<(char, char, u8), usize>,
+) -> usize {
+    let mut keypress_options = vec![];
+    for (start, end) in format!("A{}", code).chars().zip(code.chars()) {
+        keypress_options.push(get_numerical_direction_options(start, end));
+    }
+
+    let mut total = 0;
+    for options in keypress_options {
+        let mut option_counts = vec![];
+        for option in options {
+            let mut count = 0;
+            for (start, end) in format!("A{}", option).chars().zip(option.chars()) {
+                count += get_directional_keypress_count(start, end, n_robots, memo);
+            }
+            option_counts.push(count);
+        }
+        total += option_counts.iter().min().unwrap();
+    }
+
+    total
+}
+
+/// A door code: the keys to press, digits followed by `A`, and the number
+/// the digits spell.
+#[derive(Clone, Debug)]
+pub struct Code {
+    keys: String,
+    number: usize,
+}
+
+fn solve(codes: &[Code], n_robots: u8) -> Result<usize> {
+    if n_robots == 0 {
+        return Err(Error::new("there must be at least one robot"));
+    }
+    let mut memo = Memo::new("keypresses");
+    codes.iter().try_fold(0_usize, |total, code| {
+        get_human_keypress_count(&code.keys, n_robots, &mut memo)
+            .checked_mul(code.number)
+            .and_then(|complexity| total.checked_add(complexity))
+            .ok_or_else(|| Error::new(format!("the complexity of {} overflows", code.keys)))
+    })
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Keypad Conundrum";
+    const PARAMS: &'static [&'static str] = &["n_robots"];
+
+    type Input = Vec<Code>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+            .map(|line| {
+                let digits = line.strip_suffix('A').unwrap_or_default();
+                if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return fail_at(input, line, "expected a code like 029A");
+                }
+                Ok(Code {
+                    keys: line.to_string(),
+                    number: parse_at(input, digits)?,
+                })
+            })
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+        let codes = (0..size).map(|_| format!("{}A", generate::word(&mut rng, &digits, 3)));
+        Some(generate::lines(codes))
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Result<usize> {
+        solve(input, params.get("n_robots", 3)?)
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Result<usize> {
+        solve(input, params.get("n_robots", 26)?)
+    }
+}
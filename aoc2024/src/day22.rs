@@ -0,0 +1,81 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::Rng;
+
+use crate::error::{Error, Result, parse_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+use crate::trace;
+
+fn next_secret(mut secret: isize) -> isize {
+    secret ^= secret << 6;
+    secret %= 1 << 24;
+    secret ^= secret >> 5;
+    secret %= 1 << 24;
+    secret ^= secret << 11;
+    secret %= 1 << 24;
+
+    secret
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Market";
+
+    type Input = Vec<isize>;
+    type Output1 = isize;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+            .map(|line| parse_at(input, line))
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        Some(generate::lines(
+            (0..size).map(|_| rng.random_range(1..1 << 24)),
+        ))
+    }
+
+    fn part_1(secrets: &Self::Input, _params: &Params) -> Result<isize> {
+        Ok(secrets
+            .iter()
+            .map(|secret| (0..2000).fold(*secret, |acc, _| next_secret(acc)))
+            .sum())
+    }
+
+    fn part_2(secrets: &Self::Input, _params: &Params) -> Result<isize> {
+        let mut sequence_totals = HashMap::new();
+        for &secret in secrets {
+            let mut old_secret = secret;
+            let mut encountered = HashSet::new();
+            let mut current_sequence = (0, 0, 0, 0);
+            for k in 0..2000 {
+                let new_secret = next_secret(old_secret);
+                current_sequence = (
+                    current_sequence.1,
+                    current_sequence.2,
+                    current_sequence.3,
+                    (new_secret % 10) - (old_secret % 10),
+                );
+                trace!("price change", secret, k, sequence = current_sequence);
+                if k >= 3 && !encountered.contains(&current_sequence) {
+                    encountered.insert(current_sequence);
+                    let total = sequence_totals.entry(current_sequence).or_insert(0);
+                    *total += new_secret % 10;
+                }
+                old_secret = new_secret;
+            }
+        }
+
+        sequence_totals
+            .into_values()
+            .max()
+            .ok_or_else(|| Error::new("no buyers"))
+    }
+}
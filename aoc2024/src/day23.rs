@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+
+use rand::seq::{IndexedRandom, SliceRandom};
+
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+fn group_connections(
+    connection_list: Vec<(String, String)>,
+) -> std::collections::HashMap<String, std::collections::HashSet<String>> {
+    let mut connections = std::collections::HashMap::new();
+    for (c1, c2) in connection_list {
+        if !connections.contains_key(&c1) {
+            connections.insert(c1.clone(), std::collections::HashSet::new());
+        }
+        connections.get_mut(&c1).unwrap().insert(c2.clone());
+        if !connections.contains_key(&c2) {
+            connections.insert(c2.clone(), std::collections::HashSet::new());
+        }
+        connections.get_mut(&c2).unwrap().insert(c1.clone());
+    }
+    connections
+}
+
+/// The largest clique extending `clique` with vertices from `candidates` and
+/// not from `excluded`, by Bron-Kerbosch with a pivot: any maximal clique
+/// holds the pivot or one of its non-neighbours.
+fn find_maximum_clique(
+    connections: &std::collections::HashMap<String, std::collections::HashSet<String>>,
+    clique: &mut Vec<String>,
+    mut candidates: std::collections::HashSet<String>,
+    mut excluded: std::collections::HashSet<String>,
+) -> Vec<String> {
+    let Some(pivot) = candidates
+        .union(&excluded)
+        .max_by_key(|vertex| (&connections[*vertex] & &candidates).len())
+    else {
+        return clique.clone();
+    };
+    let mut biggest = clique.clone();
+    for vertex in &candidates - &connections[pivot] {
+        clique.push(vertex.clone());
+        let found = find_maximum_clique(
+            connections,
+            clique,
+            &candidates & &connections[&vertex],
+            &excluded & &connections[&vertex],
+        );
+        clique.pop();
+        if found.len() > biggest.len() {
+            biggest = found;
+        }
+        candidates.remove(&vertex);
+        excluded.insert(vertex);
+    }
+
+    biggest
+}
+
+/// The most computers the part 1 reference takes on, as it tries every
+/// triangle.
+const BRUTE_FORCE_COMPUTERS: usize = 200;
+
+/// The computers in order, each with the indices of those it connects to.
+fn adjacency(connection_list: &[(String, String)]) -> (Vec<&str>, Vec<HashSet<usize>>) {
+    let mut computers = connection_list
+        .iter()
+        .flat_map(|(c1, c2)| [c1.as_str(), c2.as_str()])
+        .collect::<Vec<_>>();
+    computers.sort();
+    computers.dedup();
+    let index = |name: &str| computers.binary_search(&name).unwrap();
+    let mut links = vec![HashSet::new(); computers.len()];
+    for (c1, c2) in connection_list {
+        links[index(c1)].insert(index(c2));
+        links[index(c2)].insert(index(c1));
+    }
+    (computers, links)
+}
+
+/// The most computers the part 2 reference takes on, as it tries every set of
+/// them.
+const SUBSET_COMPUTERS: usize = 20;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "LAN Party";
+
+    type Input = Vec<(String, String)>;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+            .map(|line| {
+                let (c1, c2) = parse::split_once(input, line, "-", "a connection like kh-tc")?;
+                Ok((c1.to_string(), c2.to_string()))
+            })
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let mut names = ('a'..='z')
+            .flat_map(|a| ('a'..='z').map(move |b| format!("{a}{b}")))
+            .collect::<Vec<_>>();
+        names.shuffle(&mut rng);
+        names.truncate(size.clamp(16, names.len()));
+        // Plant the LAN party: every pair of its computers is connected, and
+        // each has a connection of its own outside of it. The rest form
+        // triangles joined up in a ring, so that no other set of more than
+        // three computers is all connected and the party is the largest.
+        let (party, others) = names.split_at((names.len() / 4).clamp(5, 13));
+        let mut connections = HashSet::new();
+        let mut connect = |a: &String, b: &String| {
+            if a != b && !connections.contains(&format!("{b}-{a}")) {
+                connections.insert(format!("{a}-{b}"));
+            }
+        };
+        let outside = others.choose_multiple(&mut rng, party.len());
+        for ((k, a), b) in party.iter().enumerate().zip(outside) {
+            for c in &party[k + 1..] {
+                connect(a, c);
+            }
+            connect(a, b);
+        }
+        for triangle in others.chunks(3) {
+            for (k, a) in triangle.iter().enumerate() {
+                for b in &triangle[k + 1..] {
+                    connect(a, b);
+                }
+            }
+        }
+        for (a, b) in others.iter().zip(others.iter().cycle().skip(1)) {
+            connect(a, b);
+        }
+        let mut connections = connections.into_iter().collect::<Vec<_>>();
+        connections.sort();
+        connections.shuffle(&mut rng);
+        Some(generate::lines(connections))
+    }
+
+    fn part_1(data: &Self::Input, _params: &Params) -> Result<usize> {
+        let connections = group_connections(data.clone());
+
+        let mut groups = HashSet::new();
+        for (c1, connection_set) in connections.iter() {
+            if !c1.starts_with("t") {
+                continue;
+            }
+
+            for c2 in connection_set {
+                for c3 in connection_set {
+                    if c2 == c3 {
+                        continue;
+                    }
+                    if connections[c2].contains(c3) {
+                        let mut group = vec![c1, c2, c3];
+                        group.sort();
+                        groups.insert(group);
+                    }
+                }
+            }
+        }
+        Ok(groups.len())
+    }
+
+    fn part_2(data: &Self::Input, _params: &Params) -> Result<String> {
+        let connections = group_connections(data.clone());
+
+        let mut result = find_maximum_clique(
+            &connections,
+            &mut Vec::new(),
+            connections.keys().cloned().collect(),
+            HashSet::new(),
+        );
+        result.sort();
+        Ok(result.join(",").to_string())
+    }
+
+    fn reference_1(data: &Self::Input, _params: &Params) -> Option<Result<usize>> {
+        let (computers, links) = adjacency(data);
+        if computers.len() > BRUTE_FORCE_COMPUTERS {
+            return None;
+        }
+        let mut groups = 0;
+        for a in 0..computers.len() {
+            for b in a + 1..computers.len() {
+                for c in b + 1..computers.len() {
+                    let connected =
+                        links[a].contains(&b) && links[a].contains(&c) && links[b].contains(&c);
+                    if connected && [a, b, c].iter().any(|&k| computers[k].starts_with('t')) {
+                        groups += 1;
+                    }
+                }
+            }
+        }
+        Some(Ok(groups))
+    }
+
+    fn reference_2(data: &Self::Input, _params: &Params) -> Option<Result<String>> {
+        let (computers, links) = adjacency(data);
+        if computers.len() > SUBSET_COMPUTERS {
+            return None;
+        }
+        // Sets of computers as bit masks: a LAN party is a set in which every
+        // computer connects to all the others.
+        let n = computers.len();
+        let neighbours = links
+            .iter()
+            .map(|links| links.iter().fold(0_u32, |set, &k| set | 1 << k))
+            .collect::<Vec<_>>();
+        let is_party = |set: u32| {
+            (0..n)
+                .filter(|&k| set >> k & 1 == 1)
+                .all(|k| set & !(1 << k) & !neighbours[k] == 0)
+        };
+        let mut largest = vec![0_u32];
+        for set in 1..1_u32 << n {
+            let size = set.count_ones();
+            if size < largest[0].count_ones() || !is_party(set) {
+                continue;
+            }
+            if size > largest[0].count_ones() {
+                largest.clear();
+            }
+            largest.push(set);
+        }
+        // The puzzle promises a single largest LAN party.
+        let [party] = largest[..] else {
+            return Some(Err(Error::new("there is more than one largest LAN party")));
+        };
+        let names = (0..n)
+            .filter(|&k| party >> k & 1 == 1)
+            .map(|k| computers[k])
+            .collect::<Vec<_>>();
+        Some(Ok(names.join(",")))
+    }
+}
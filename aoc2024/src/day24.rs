@@ -0,0 +1,821 @@
+use itertools::Itertools;
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use std::cmp;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::Range;
+
+use crate::error::{Error, Result, fail_at, parse_at};
+use crate::generate;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GateType {
+    And,
+    Or,
+    Xor,
+}
+
+type Gates = HashMap<String, (GateType, String, String)>;
+
+fn get_gate_output(
+    name: &str,
+    inputs: &HashMap<String, bool>,
+    gates: &Gates,
+    outputs: &mut HashMap<String, bool>,
+) -> bool {
+    let (operation, g1, g2) = gates
+        .get(name)
+        .unwrap_or_else(|| panic!("Gate not found: {name}"));
+    match outputs.get(name) {
+        None => {
+            let input_1 = if inputs.contains_key(g1) {
+                *inputs.get(g1).unwrap()
+            } else {
+                get_gate_output(g1, inputs, gates, outputs)
+            };
+            let input_2 = if inputs.contains_key(g2) {
+                *inputs.get(g2).unwrap()
+            } else {
+                get_gate_output(g2, inputs, gates, outputs)
+            };
+            let output = match operation {
+                GateType::And => input_1 && input_2,
+                GateType::Or => input_1 || input_2,
+                GateType::Xor => input_1 ^ input_2,
+            };
+            outputs.insert(name.to_string(), output);
+            output
+        }
+        Some(o) => *o,
+    }
+}
+
+fn get_input_register(name: &str, inputs: &HashMap<String, bool>) -> usize {
+    let mut value = 0;
+    for (input_name, input_value) in inputs.iter() {
+        if input_name.starts_with(name) && *input_value {
+            value += 1 << (input_name[1..].parse::<usize>().unwrap());
+        }
+    }
+    value
+}
+
+fn get_output_register(
+    inputs: &HashMap<String, bool>,
+    gates: &Gates,
+    outputs: &mut HashMap<String, bool>,
+) -> usize {
+    let mut value = 0;
+    for gate_name in gates.keys() {
+        if gate_name.starts_with("z") && get_gate_output(gate_name, inputs, gates, outputs) {
+            value += 1 << (gate_name[1..].parse::<usize>().unwrap());
+        }
+    }
+    value
+}
+
+fn get_all_gate_parents(gate_name: String, gates: &Gates) -> HashSet<String> {
+    if !gates.contains_key(&gate_name) {
+        return HashSet::new();
+    }
+    let (_, input_1, input_2) = gates.get(&gate_name).unwrap();
+    let mut parents = HashSet::from([input_1.to_string(), input_2.to_string()]);
+    parents.extend(get_all_gate_parents(input_1.to_string(), gates));
+    parents.extend(get_all_gate_parents(input_2.to_string(), gates));
+
+    parents
+}
+
+fn can_swap_gates(gate_name_1: String, gate_name_2: String, gates: &Gates) -> bool {
+    let parents_1 = get_all_gate_parents(gate_name_1.clone(), gates);
+    let parents_2 = get_all_gate_parents(gate_name_2.clone(), gates);
+
+    !parents_2.contains(&gate_name_1) && !parents_1.contains(&gate_name_2)
+}
+
+fn swap_gate_outputs(gate_name_1: String, gate_name_2: String, gates: &Gates) -> Gates {
+    let mut new_gates = gates.clone();
+
+    let gate_1 = gates.get(&gate_name_1).unwrap().clone();
+    let gate_2 = gates.get(&gate_name_2).unwrap().clone();
+    new_gates.insert(gate_name_1, gate_2.clone());
+    new_gates.insert(gate_name_2, gate_1.clone());
+
+    new_gates
+}
+
+fn get_suspicious_gates(gates: &Gates) -> Result<HashSet<String>> {
+    let max_z = gates
+        .keys()
+        .filter(|k| k.starts_with("z"))
+        .map(|k| k[1..].parse::<usize>().unwrap())
+        .max()
+        .ok_or_else(|| Error::new("no z wires"))?;
+
+    let mut suspicious = HashSet::new();
+    // All but last output wire must be from XOR Gate
+    for k in 0..(max_z - 1) {
+        let gate_name = format!("z{k:02}");
+        if let Some((gate_type, _, _)) = gates.get(&gate_name)
+            && *gate_type != GateType::Xor
+        {
+            suspicious.insert(gate_name);
+        }
+    }
+
+    // Last output wire is from an OR gate
+    let gate_name = format!("z{max_z:02}");
+    if let Some((gate_type, _, _)) = gates.get(&gate_name)
+        && *gate_type != GateType::Or
+    {
+        suspicious.insert(gate_name);
+    }
+
+    for (gate_name, (gate_type, input_1, input_2)) in gates.iter() {
+        if *gate_type != GateType::Xor
+            || (input_1.starts_with("x") && input_2.starts_with("y"))
+            || (input_1.starts_with("y") && input_2.starts_with("x"))
+        {
+            continue;
+        }
+
+        // XOR gates take x and y wires or output z wire
+        if !gate_name.starts_with("z") {
+            suspicious.insert(gate_name.to_string());
+        }
+    }
+
+    for (gate_name, (gate_type, input_1, input_2)) in gates.iter() {
+        // XOR only takes an input bit if a XOR follows it, unless the input bits are the first bits
+        if *gate_type != GateType::Xor
+            || !((input_1.starts_with("x") && input_2.starts_with("y"))
+                || (input_1.starts_with("y") && input_2.starts_with("x")))
+            || [("x00", "y00"), ("y00", "x00")].contains(&(input_1.as_str(), input_2.as_str()))
+        {
+            continue;
+        }
+
+        let connecting_gates = gates
+            .values()
+            .filter(|g| g.1 == *gate_name || g.2 == *gate_name);
+        if connecting_gates.filter(|g| g.0 == GateType::Xor).count() != 1 {
+            suspicious.insert(gate_name.to_string());
+        }
+    }
+
+    // AND gate only connect to OR gates unless inputs are x and y wires
+    for (gate_name, (gate_type, input_1, input_2)) in gates.iter() {
+        if *gate_type != GateType::And
+            || [("x00", "y00"), ("y00", "x00")].contains(&(input_1.as_str(), input_2.as_str()))
+        {
+            continue;
+        }
+        let connecting_gates = gates
+            .values()
+            .filter(|g| g.1 == *gate_name || g.2 == *gate_name);
+        if connecting_gates.filter(|g| g.0 != GateType::Or).count() > 0 {
+            suspicious.insert(gate_name.to_string());
+        }
+    }
+
+    Ok(suspicious)
+}
+
+/// Checks that a register wire like `x03` is numbered, as the register
+/// values are read off those numbers.
+fn check_wire_name(input: &str, name: &str) -> Result<()> {
+    if name.starts_with(['x', 'y', 'z']) {
+        parse_at::<usize>(input, &name[1..])?;
+    }
+    Ok(())
+}
+
+/// The widest adder the reference checks on every pair of inputs.
+const BRUTE_FORCE_BITS: usize = 8;
+
+/// For each power of two below 64, the lanes of a word whose index has that
+/// bit set: the values of the low input bits when a word evaluates 64
+/// inputs side by side.
+const LANE_BITS: [u64; 6] = [
+    0xAAAA_AAAA_AAAA_AAAA,
+    0xCCCC_CCCC_CCCC_CCCC,
+    0xF0F0_F0F0_F0F0_F0F0,
+    0xFF00_FF00_FF00_FF00,
+    0xFFFF_0000_FFFF_0000,
+    0xFFFF_FFFF_0000_0000,
+];
+
+/// The gates with their wires numbered, for the reference to rewire and
+/// evaluate quickly.
+struct Circuit {
+    names: Vec<String>,
+    gates: Vec<Option<(GateType, usize, usize)>>,
+    x: Vec<usize>,
+    y: Vec<usize>,
+    z: Vec<usize>,
+}
+
+impl Circuit {
+    fn new(inputs: &HashMap<String, bool>, gates: &Gates) -> Circuit {
+        let mut names = inputs
+            .keys()
+            .chain(gates.keys())
+            .cloned()
+            .collect::<Vec<_>>();
+        names.sort();
+        let index = |name: &str| names.binary_search_by(|n| n.as_str().cmp(name)).unwrap();
+        let wires = |prefix: char| {
+            let mut wires = names
+                .iter()
+                .enumerate()
+                .filter(|(_, name)| name.starts_with(prefix))
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>();
+            wires.sort_by_key(|&k| names[k][1..].parse::<usize>().unwrap());
+            wires
+        };
+        let circuit_gates = names
+            .iter()
+            .map(|name| {
+                let (gate_type, input_1, input_2) = gates.get(name)?;
+                Some((gate_type.clone(), index(input_1), index(input_2)))
+            })
+            .collect();
+        Circuit {
+            x: wires('x'),
+            y: wires('y'),
+            z: wires('z'),
+            gates: circuit_gates,
+            names,
+        }
+    }
+
+    /// The gates in an order that evaluates each after its inputs, or
+    /// `None` when the wiring loops.
+    fn order(&self) -> Option<Vec<usize>> {
+        let mut order = Vec::new();
+        let mut state = vec![0_u8; self.names.len()];
+        for wire in 0..self.names.len() {
+            let mut stack = vec![(wire, false)];
+            while let Some((wire, inputs_done)) = stack.pop() {
+                match (state[wire], inputs_done) {
+                    (2, _) => {}
+                    (_, true) => {
+                        state[wire] = 2;
+                        order.push(wire);
+                    }
+                    (1, false) => return None,
+                    _ => {
+                        state[wire] = 1;
+                        stack.push((wire, true));
+                        if let Some((_, input_1, input_2)) = self.gates[wire] {
+                            stack.extend([(input_1, false), (input_2, false)]);
+                        }
+                    }
+                }
+            }
+        }
+        Some(order)
+    }
+
+    /// The lowest output bit that differs from the sum of x and y, for every
+    /// x and y made of the `varied` bits, with `Some(None)` when there is
+    /// none and `None` when the wiring loops.
+    fn lowest_wrong_bit(&self, varied: Range<usize>) -> Option<Option<usize>> {
+        let order = self.order()?;
+        let bits = varied.len();
+        let pairs = 1_u64 << (2 * bits);
+        let used_lanes = if pairs < 64 { (1 << pairs) - 1 } else { !0 };
+        let mut values = vec![0_u64; self.names.len()];
+        let mut lowest = None;
+        for word in 0..pairs.div_ceil(64) {
+            // Bit k of a pair's index is bit k of x, and bit k + bits of y.
+            let input_bit = |k: usize| match k {
+                0..6 => LANE_BITS[k],
+                _ if (word >> (k - 6)) & 1 == 1 => !0,
+                _ => 0,
+            };
+            for (k, (&x, &y)) in self.x.iter().zip(&self.y).enumerate() {
+                let (x_bit, y_bit) = match k.checked_sub(varied.start) {
+                    Some(k) if k < bits => (input_bit(k), input_bit(k + bits)),
+                    _ => (0, 0),
+                };
+                values[x] = x_bit;
+                values[y] = y_bit;
+            }
+            for &wire in &order {
+                if let Some((gate_type, input_1, input_2)) = &self.gates[wire] {
+                    let (a, b) = (values[*input_1], values[*input_2]);
+                    values[wire] = match gate_type {
+                        GateType::And => a & b,
+                        GateType::Or => a | b,
+                        GateType::Xor => a ^ b,
+                    };
+                }
+            }
+            let mut carry = 0;
+            for (k, &z) in self.z.iter().enumerate() {
+                let (x, y) = match (self.x.get(k), self.y.get(k)) {
+                    (Some(&x), Some(&y)) => (values[x], values[y]),
+                    _ => (0, 0),
+                };
+                if (values[z] ^ x ^ y ^ carry) & used_lanes != 0 {
+                    lowest = Some(lowest.map_or(k, |lowest: usize| lowest.min(k)));
+                    break;
+                }
+                carry = (x & y) | (carry & (x ^ y));
+            }
+        }
+        Some(lowest)
+    }
+
+    /// The value of `wire` on the test lanes, with every gate as it is now,
+    /// or `Err` with the gates being worked out when they loop.
+    fn evaluate(&self, wire: usize, test: &Test, memo: &mut Memo) -> std::result::Result<u64, u64> {
+        if let Some(value) = memo.known[wire] {
+            return Ok(value);
+        }
+        let Some((gate_type, input_1, input_2)) = &self.gates[wire] else {
+            return Ok(test.inputs[wire]);
+        };
+        if memo.visiting & 1 << wire != 0 {
+            return Err(memo.visiting);
+        }
+        memo.visiting |= 1 << wire;
+        let a = self.evaluate(*input_1, test, memo)?;
+        let b = self.evaluate(*input_2, test, memo)?;
+        memo.visiting &= !(1 << wire);
+        let value = match gate_type {
+            GateType::And => a & b,
+            GateType::Or => a | b,
+            GateType::Xor => a ^ b,
+        };
+        memo.known[wire] = Some(value);
+        Ok(value)
+    }
+
+    /// Sets of gates that each have to change for the output bits to come
+    /// out right on the test lanes, with every gate as it is now: for a bit
+    /// that loops, the gates leading into the loop, and for a bit that is
+    /// wrong on a lane, the gates it is sensitive to on that lane.
+    fn wrong_sets(&self, test: &Test) -> Vec<u64> {
+        let mut memo = Memo::default();
+        let mut sets = Vec::new();
+        for (&z, &sum) in self.z.iter().zip(&test.sums) {
+            memo.visiting = 0;
+            match self.evaluate(z, test, &mut memo) {
+                Ok(value) => {
+                    for lane in wires(value ^ sum) {
+                        sets.push(self.sensitive(z, lane, test, &memo, &mut [None; 64]));
+                    }
+                }
+                Err(path) => sets.push(path),
+            }
+        }
+        sets
+    }
+
+    /// The gates whose change could change `wire` on a lane, keeping to an
+    /// input that settles a gate on its own where there is one, as the gate
+    /// stays settled unless that input changes.
+    fn sensitive(
+        &self,
+        wire: usize,
+        lane: usize,
+        test: &Test,
+        memo: &Memo,
+        seen: &mut [Option<u64>; 64],
+    ) -> u64 {
+        let Some((gate_type, input_1, input_2)) = &self.gates[wire] else {
+            return 0;
+        };
+        if let Some(set) = seen[wire] {
+            return set;
+        }
+        let value = |wire: usize| memo.known[wire].unwrap_or(test.inputs[wire]) >> lane & 1;
+        let settling = match gate_type {
+            GateType::And => Some(0),
+            GateType::Or => Some(1),
+            GateType::Xor => None,
+        };
+        let settles = |wire: usize| Some(value(wire)) == settling;
+        let mut set = |wire: usize| self.sensitive(wire, lane, test, memo, seen);
+        let (input_1, input_2) = (*input_1, *input_2);
+        let inputs = match (settles(input_1), settles(input_2)) {
+            (true, true) => {
+                let (set_1, set_2) = (set(input_1), set(input_2));
+                cmp::min_by_key(set_1, set_2, |set| set.count_ones())
+            }
+            (true, false) => set(input_1),
+            (false, true) => set(input_2),
+            (false, false) => set(input_1) | set(input_2),
+        };
+        let set = inputs | 1 << wire;
+        seen[wire] = Some(set);
+        set
+    }
+
+    /// Whether every output bit is right on the test lanes.
+    fn adds_on(&self, test: &Test) -> bool {
+        let mut memo = Memo::default();
+        self.z.iter().zip(&test.sums).all(|(&z, &sum)| {
+            memo.visiting = 0;
+            self.evaluate(z, test, &mut memo) == Ok(sum)
+        })
+    }
+
+    /// Adds the swapped wires to `found` if the circuit now adds on every
+    /// pair of inputs.
+    fn record(&self, swapped: &[usize], found: &mut BTreeSet<String>) {
+        if self.lowest_wrong_bit(0..self.x.len()) == Some(None) {
+            let mut names = swapped
+                .iter()
+                .map(|&k| self.names[k].clone())
+                .collect::<Vec<_>>();
+            names.sort();
+            found.insert(names.join(","));
+        }
+    }
+
+    /// Every way of swapping the outputs of `swaps_left` more pairs of gates
+    /// not in `decided` that makes the circuit add, as the sorted names of
+    /// the swapped wires. Sets of wires are bit masks.
+    ///
+    /// A wrong bit stays wrong unless a gate it is sensitive to changes, so
+    /// each set of such gates, here or `required` by an earlier step, has to
+    /// hold a gate yet to be swapped. The gates of the smallest set each in
+    /// turn swap with every gate left, those before it keeping their outputs.
+    /// Branches are only cut when the swaps left cannot reach every set, and
+    /// every set of swaps found to add on the test lanes is checked on every
+    /// pair of inputs.
+    fn repairs(
+        &mut self,
+        swaps_left: usize,
+        required: &[u64],
+        decided: u64,
+        swapped: &mut Vec<usize>,
+        test: &Test,
+        found: &mut BTreeSet<String>,
+    ) {
+        let mut all = required
+            .iter()
+            .chain(&self.wrong_sets(test))
+            .map(|set| set & !decided)
+            .collect::<Vec<_>>();
+        all.sort_by_key(|set| set.count_ones());
+        // A set holding a smaller one is reached whenever that one is.
+        let mut sets = Vec::new();
+        for set in all {
+            if sets.iter().all(|&smaller| set & smaller != smaller) {
+                sets.push(set);
+            }
+        }
+        if swaps_left == 0 {
+            if sets.is_empty() {
+                self.record(swapped, found);
+            }
+            return;
+        }
+        if !can_hit(&sets, 2 * swaps_left) {
+            return;
+        }
+        let gates = (0..self.names.len())
+            .filter(|&k| self.gates[k].is_some())
+            .fold(0_u64, |set, k| set | 1 << k);
+        // With every bit right, swaps may still be made that keep it so.
+        let first = sets.first().copied().unwrap_or(gates & !decided);
+        let mut kept = decided;
+        for wire in wires(first) {
+            kept |= 1 << wire;
+            // The last swap has to change a gate in every set `wire` misses.
+            let others = match swaps_left {
+                1 => sets
+                    .iter()
+                    .filter(|&set| set & 1 << wire == 0)
+                    .fold(gates & !kept, |others, set| others & set),
+                _ => gates & !kept,
+            };
+            for other in wires(others) {
+                let changed = 1 << wire | 1 << other;
+                let rest = sets
+                    .iter()
+                    .copied()
+                    .filter(|set| set & changed == 0)
+                    .collect::<Vec<_>>();
+                if !can_hit(&rest, 2 * (swaps_left - 1)) {
+                    continue;
+                }
+                self.gates.swap(wire, other);
+                swapped.extend([wire, other]);
+                if swaps_left == 1 {
+                    if self.adds_on(test) {
+                        self.record(swapped, found);
+                    }
+                } else {
+                    self.repairs(swaps_left - 1, &rest, kept | changed, swapped, test, found);
+                }
+                swapped.truncate(swapped.len() - 2);
+                self.gates.swap(wire, other);
+            }
+        }
+    }
+}
+
+/// The wires in a set.
+fn wires(set: u64) -> impl Iterator<Item = usize> {
+    (0..64).filter(move |k| set & 1 << k != 0)
+}
+
+/// Whether some `count` wires between them hold one of every set, the sets
+/// sorted by size. Exact for up to two wires, and otherwise only ruling out
+/// more sets with nothing in common than there are wires.
+fn can_hit(sets: &[u64], count: usize) -> bool {
+    match (sets.first(), count) {
+        (None, _) => true,
+        (Some(_), 0) => false,
+        (Some(&first), 1 | 2) => wires(first).any(|k| {
+            let rest = sets
+                .iter()
+                .copied()
+                .filter(|set| set & 1 << k == 0)
+                .collect::<Vec<_>>();
+            can_hit(&rest, count - 1)
+        }),
+        _ => {
+            let mut disjoint = Vec::new();
+            for &set in sets {
+                if disjoint.iter().all(|other| set & other == 0) {
+                    disjoint.push(set);
+                }
+            }
+            disjoint.len() <= count
+        }
+    }
+}
+
+/// Random inputs side by side in the lanes of a word, with the bits of their
+/// sums, for the reference to rule out wrong wirings cheaply.
+struct Test {
+    inputs: Vec<u64>,
+    sums: Vec<u64>,
+}
+
+impl Test {
+    fn new(circuit: &Circuit) -> Test {
+        let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+        let mut random = || {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut inputs = vec![0; circuit.names.len()];
+        let mut sums = Vec::new();
+        let mut carry = 0;
+        for (&x, &y) in circuit.x.iter().zip(&circuit.y) {
+            let (a, b) = (random(), random());
+            inputs[x] = a;
+            inputs[y] = b;
+            sums.push(a ^ b ^ carry);
+            carry = (a & b) | (carry & (a ^ b));
+        }
+        sums.push(carry);
+        Test { inputs, sums }
+    }
+}
+
+/// The values of the wires worked out so far, and the gates being worked
+/// out.
+struct Memo {
+    known: [Option<u64>; 64],
+    visiting: u64,
+}
+
+impl Default for Memo {
+    fn default() -> Memo {
+        Memo {
+            known: [None; 64],
+            visiting: 0,
+        }
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Crossed Wires";
+
+    type Input = (HashMap<String, bool>, Gates);
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (part1, part2) = parse::two_blocks(input, input)?;
+
+        let mut inputs = HashMap::new();
+        for line in parse::lines(part1) {
+            let (name, value) = parse::split_once(input, line, ": ", "an input like x00: 1")?;
+            check_wire_name(input, name)?;
+            inputs.insert(name.to_string(), value == "1");
+        }
+
+        let mut gates = HashMap::new();
+        let mut gate_inputs = Vec::new();
+        for line in parse::lines(part2) {
+            let [input_1, gate_type, input_2, "->", name] = line.split(" ").collect::<Vec<_>>()[..]
+            else {
+                return fail_at(input, line, "expected a gate like x00 AND y00 -> z00");
+            };
+            let gate_type = match gate_type {
+                "AND" => GateType::And,
+                "OR" => GateType::Or,
+                "XOR" => GateType::Xor,
+                _ => return fail_at(input, gate_type, "unknown gate type"),
+            };
+            check_wire_name(input, name)?;
+            gate_inputs.extend([input_1, input_2]);
+            gates.insert(
+                name.to_string(),
+                (gate_type, input_1.to_string(), input_2.to_string()),
+            );
+        }
+
+        for wire in gate_inputs {
+            if !inputs.contains_key(wire) && !gates.contains_key(wire) {
+                return fail_at(input, wire, format!("wire {wire} is never set"));
+            }
+        }
+
+        Ok((inputs, gates))
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let bits = size.clamp(8, 45);
+        let register = |name: char, bit: usize| format!("{name}{bit:02}");
+        let letters = ('a'..='w').collect::<Vec<_>>();
+        let mut wires = HashSet::new();
+        while wires.len() < 4 * bits {
+            wires.insert(generate::word(&mut rng, &letters, 3));
+        }
+        let mut wires = wires.into_iter().collect::<Vec<_>>();
+        wires.sort();
+        wires.shuffle(&mut rng);
+
+        // A ripple-carry adder, with the sum and the carry of each bit
+        // worked out from the carry of the bit below.
+        let mut gates = vec![
+            [
+                register('x', 0),
+                "XOR".into(),
+                register('y', 0),
+                register('z', 0),
+            ],
+            [
+                register('x', 0),
+                "AND".into(),
+                register('y', 0),
+                wires[0].clone(),
+            ],
+        ];
+        let mut swaps = Vec::new();
+        for bit in 1..bits {
+            let [sum, and, both, carry] = [0, 1, 2, 3].map(|k| wires[4 * bit + k - 3].clone());
+            let previous = gates[gates.len() - 1][3].clone();
+            let carry = if bit == bits - 1 {
+                register('z', bits)
+            } else {
+                carry
+            };
+            let (x, y, z) = (register('x', bit), register('y', bit), register('z', bit));
+            swaps.push([
+                [z.clone(), carry.clone()],
+                [z.clone(), both.clone()],
+                [sum.clone(), and.clone()],
+            ]);
+            gates.extend([
+                [x.clone(), "XOR".into(), y.clone(), sum.clone()],
+                [x, "AND".into(), y, and.clone()],
+                [sum.clone(), "XOR".into(), previous.clone(), z],
+                [sum, "AND".into(), previous, both.clone()],
+                [and, "OR".into(), both, carry],
+            ]);
+        }
+
+        // Swap the outputs of four pairs of gates within different bits, in
+        // the ways the puzzle does.
+        for swap in swaps[1..bits - 2].choose_multiple(&mut rng, 4) {
+            let [a, b] = swap.choose(&mut rng).unwrap();
+            for gate in gates.iter_mut() {
+                if gate[3] == *a {
+                    gate[3] = b.clone();
+                } else if gate[3] == *b {
+                    gate[3] = a.clone();
+                }
+            }
+        }
+        gates.shuffle(&mut rng);
+
+        let inputs = ['x', 'y']
+            .into_iter()
+            .flat_map(|name| (0..bits).map(move |bit| register(name, bit)))
+            .map(|wire| format!("{wire}: {}", u8::from(rng.random_bool(0.5))))
+            .collect::<Vec<_>>();
+        let gates = gates
+            .into_iter()
+            .map(|[a, op, b, out]| match rng.random_bool(0.5) {
+                true => format!("{a} {op} {b} -> {out}"),
+                false => format!("{b} {op} {a} -> {out}"),
+            });
+        Some(format!(
+            "{}\n{}",
+            generate::lines(inputs),
+            generate::lines(gates)
+        ))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (inputs, gates) = input;
+        let mut outputs: HashMap<String, bool> = HashMap::new();
+        Ok(get_output_register(inputs, gates, &mut outputs))
+    }
+
+    fn part_2(input: &Self::Input, _params: &Params) -> Result<String> {
+        let (inputs, gates) = input;
+        let suspicious_gates = get_suspicious_gates(gates)?;
+        let combos = suspicious_gates.iter().combinations(2);
+
+        let mut answers = HashSet::new();
+        for swap_set in combos.combinations(4) {
+            let mut unique_gates = HashSet::new();
+            for swap in swap_set.clone() {
+                for gate_name in swap {
+                    unique_gates.insert(gate_name.clone());
+                }
+            }
+            if unique_gates.len() != 8 {
+                continue;
+            }
+
+            let mut fixed_gates = gates.clone();
+            let mut swap_successful = true;
+            for swap in swap_set.clone() {
+                if can_swap_gates(swap[0].clone(), swap[1].clone(), &fixed_gates) {
+                    fixed_gates = swap_gate_outputs(swap[0].clone(), swap[1].clone(), &fixed_gates);
+                } else {
+                    swap_successful = false;
+                    break;
+                }
+            }
+            if !swap_successful {
+                continue;
+            }
+
+            let mut test_passed = true;
+            for _ in 0..100 {
+                let mut outputs: HashMap<String, bool> = HashMap::new();
+                let test_inputs = inputs
+                    .keys()
+                    .map(|k| (k.to_string(), rand::random_bool(0.5)))
+                    .collect::<HashMap<_, _>>();
+                if get_input_register("x", &test_inputs) + get_input_register("y", &test_inputs)
+                    != get_output_register(&test_inputs, &fixed_gates, &mut outputs)
+                {
+                    test_passed = false;
+                    break;
+                }
+            }
+            if test_passed {
+                let mut gate_names = vec![];
+                for swap in swap_set {
+                    for gate_name in swap {
+                        gate_names.push(gate_name.clone());
+                    }
+                }
+                gate_names.sort();
+                answers.insert(gate_names.join(","));
+            }
+        }
+
+        Ok(answers.iter().join("\n"))
+    }
+
+    fn reference_2(input: &Self::Input, _params: &Params) -> Option<Result<String>> {
+        let (inputs, gates) = input;
+        let mut circuit = Circuit::new(inputs, gates);
+        if circuit.x.len() > BRUTE_FORCE_BITS
+            || circuit.x.len() != circuit.y.len()
+            || circuit.names.len() > 64
+        {
+            return None;
+        }
+        let test = Test::new(&circuit);
+        let mut found = BTreeSet::new();
+        circuit.repairs(4, &[], 0, &mut Vec::new(), &test, &mut found);
+        Some(Ok(found.iter().join("\n")))
+    }
+}
@@ -0,0 +1,108 @@
+use rand::Rng;
+
+use crate::error::{Error, Result};
+use crate::generate;
+use crate::grid::Grid;
+use crate::parse;
+use crate::solution::{Params, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Code Chronicle";
+    const PARTS: u8 = 1;
+
+    type Input = (Vec<[u8; 5]>, Vec<[u8; 5]>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+
+        for lock_or_key in parse::blocks(input) {
+            let grid = Grid::parse(lock_or_key, |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
+            .map_err(|err| err.within(input, lock_or_key))?;
+            if (grid.width(), grid.height()) != (5, 7) {
+                return Err(Error::new("locks and keys must be 5 wide and 7 high")
+                    .at(input, lock_or_key.lines().next().unwrap_or_default()));
+            }
+            let is_lock = grid.rows().next().is_some_and(|row| row.iter().all(|c| *c));
+            // The full row at the top of a lock or the bottom of a key is
+            // not part of a pin.
+            let mut colums = [0_u8; 5];
+            for (i, column) in grid.columns().enumerate() {
+                colums[i] = (column.filter(|c| **c).count() as u8).saturating_sub(1);
+            }
+            if is_lock {
+                locks.push(colums);
+            } else {
+                keys.push(colums);
+            }
+        }
+
+        Ok((locks, keys))
+    }
+
+    fn validate(input: &str, _params: &Params) -> Vec<Error> {
+        let full = |row: &str| row.bytes().all(|c| c == b'#');
+        let empty = |row: &str| row.bytes().all(|c| c == b'.');
+        parse::blocks(input)
+            .filter_map(|schematic| {
+                let top = schematic.lines().next()?;
+                let bottom = schematic.lines().last()?;
+                let is_lock = full(top) && empty(bottom);
+                let is_key = empty(top) && full(bottom);
+                (!is_lock && !is_key).then(|| {
+                    Error::new(
+                        "neither a lock, with a full top row, nor a key, with a full bottom row",
+                    )
+                    .at(input, top)
+                })
+            })
+            .collect()
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = generate::rng(seed);
+        let schematics = (0..size).map(|_| {
+            let heights = [0; 5].map(|_| rng.random_range(0..=5));
+            let is_lock = rng.random_bool(0.5);
+            let rows = (0..7).map(|row| {
+                // Rows counted from the base of the pins: the top of a lock
+                // and the bottom of a key.
+                let from_base = if is_lock { row } else { 6 - row };
+                heights
+                    .iter()
+                    .map(|&height| if from_base <= height { '#' } else { '.' })
+                    .collect::<String>()
+            });
+            generate::lines(rows)
+        });
+        Some(generate::join(schematics, "\n"))
+    }
+
+    fn part_1(input: &Self::Input, _params: &Params) -> Result<usize> {
+        let (locks, keys) = input;
+
+        let mut total = 0;
+        for lock in locks.iter() {
+            for key in keys.iter() {
+                if lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5) {
+                    total += 1;
+                }
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn part_2(_input: &Self::Input, _params: &Params) -> Result<usize> {
+        Err(Error::new("day 25 only has one part"))
+    }
+}
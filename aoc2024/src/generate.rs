@@ -0,0 +1,125 @@
+//! Building blocks of the random input generators, see
+//! [`Solution::generate`](crate::solution::Solution::generate).
+//!
+//! Every generator draws from [`rng`], so that a seed always gives the same
+//! input, and ends its input with a newline like the puzzle inputs do.
+
+use std::fmt::Display;
+
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+use crate::grid::{Grid, ORTHOGONAL, Pos};
+
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// One line per item, each ending with a newline.
+pub fn lines<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().map(|item| format!("{item}\n")).collect()
+}
+
+/// `items` joined with `separator`.
+pub fn join<T: Display>(items: impl IntoIterator<Item = T>, separator: &str) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// A grid rendered one line per row.
+pub fn grid(grid: &Grid<char>) -> String {
+    format!("{grid}\n")
+}
+
+/// A perfect maze of walls (`true`) and passages: the cells with odd
+/// coordinates are open and joined to each other by exactly one path, and the
+/// border is wall. The sides are rounded up to odd numbers of at least 5.
+pub fn maze(rng: &mut StdRng, width: usize, height: usize) -> Grid<bool> {
+    let (width, height) = (width.max(5) | 1, height.max(5) | 1);
+    let mut walls = Grid::new(width, height, true);
+    let mut stack = vec![(1, 1)];
+    walls[(1, 1)] = false;
+    while let Some(&(x, y)) = stack.last() {
+        let mut steps = ORTHOGONAL;
+        steps.shuffle(rng);
+        let next = steps.into_iter().find_map(|(dx, dy)| {
+            let cell = walls.step((x, y), (2 * dx, 2 * dy))?;
+            (walls[cell] && cell.0 < width - 1 && cell.1 < height - 1).then_some((cell, dx, dy))
+        });
+        match next {
+            Some((cell, dx, dy)) => {
+                walls[((x as isize + dx) as usize, (y as isize + dy) as usize)] = false;
+                walls[cell] = false;
+                stack.push(cell);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    walls
+}
+
+/// Opens `count` walls of a [`maze`] that separate two passages, adding loops.
+pub fn open_walls(rng: &mut StdRng, walls: &mut Grid<bool>, count: usize) {
+    let separating = walls
+        .positions()
+        .filter(|&(x, y)| x > 0 && y > 0 && x < walls.width() - 1 && y < walls.height() - 1)
+        .filter(|&(x, y)| (x + y) % 2 == 1 && walls[(x, y)])
+        .collect::<Vec<Pos>>();
+    for &pos in separating.choose_multiple(rng, count) {
+        walls[pos] = false;
+    }
+}
+
+/// `len` letters drawn from `letters`.
+pub fn word(rng: &mut StdRng, letters: &[char], len: usize) -> String {
+    (0..len).map(|_| *letters.choose(rng).unwrap()).collect()
+}
+
+/// A number with `digits` digits, without a leading zero.
+pub fn number(rng: &mut StdRng, digits: u32) -> u64 {
+    rng.random_range(10_u64.pow(digits - 1)..10_u64.pow(digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search;
+
+    #[test]
+    fn mazes_join_every_cell() {
+        let walls = maze(&mut rng(7), 20, 12);
+        assert_eq!((walls.width(), walls.height()), (21, 13));
+        assert!(walls.rows().next().unwrap().iter().all(|wall| *wall));
+
+        let open = walls.positions().filter(|&p| !walls[p]).collect::<Vec<_>>();
+        let reached = search::bfs(
+            (1, 1),
+            |&p| {
+                walls
+                    .neighbours(p)
+                    .filter(|&n| !walls[n])
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        );
+        assert!(open.iter().all(|p| reached.cost_to(p).is_some()));
+        // A tree: the 10 x 6 cells are joined by one passage fewer.
+        assert_eq!(open.len(), 60 + 59);
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        let draw = |seed| {
+            let mut rng = rng(seed);
+            lines((0..5).map(|_| number(&mut rng, 3)))
+        };
+        assert_eq!(draw(1), draw(1));
+        assert_ne!(draw(1), draw(2));
+    }
+}
@@ -0,0 +1,213 @@
+//! Points and directions on the plane, with `y` growing downwards as in the
+//! puzzle maps.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Pos;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    /// Converts the coordinates, e.g. from grid indices to signed values.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point {
+            x: U::try_from(self.x).ok()?,
+            y: U::try_from(self.y).ok()?,
+        })
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Add<Output = T>> Point<T> {
+    pub fn manhattan(self, other: Point<T>) -> T {
+        let dist = |a: T, b: T| if a > b { a - b } else { b - a };
+        dist(self.x, other.x) + dist(self.y, other.y)
+    }
+}
+
+impl Point {
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    /// The four orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The grid position of the point, if neither coordinate is negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        self.try_cast::<usize>().map(Into::into)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> (T, T) {
+        (p.x, p.y)
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((x, y): Pos) -> Point {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// Parses the arrows `^>v<` used by the puzzles.
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let p = Point::new(3, -2);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(p * 2, Point::new(6, -4));
+        assert_eq!(-p, Point::new(-3, 2));
+        assert_eq!(p.manhattan(Point::new(0, 0)), 5);
+        assert_eq!(Point::<usize>::new(1, 5).manhattan(Point::new(4, 2)), 6);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Point::<isize>::from((2_usize, 3_usize)), Point::new(2, 3));
+        assert_eq!(Point::new(2, 3).to_pos(), Some((2, 3)));
+        assert_eq!(Point::new(-1, 3).to_pos(), None);
+        assert_eq!(Point::<i64>::new(300, 1).try_cast::<u8>(), None);
+    }
+
+    #[test]
+    fn turning() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.reverse().delta(), -d.delta());
+            assert_eq!(Point::new(0, 0).step(d).step(d.reverse()), Point::new(0, 0));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::from_arrow('<'), Some(Direction::Left));
+    }
+}
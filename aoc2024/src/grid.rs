@@ -0,0 +1,310 @@
+//! A rectangular grid of cells, as used by most of the map-based days.
+//!
+//! Positions are `(x, y)` with `x` the column and `y` the row, both counted
+//! from the top left.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::geometry::Point;
+
+pub type Pos = (usize, usize);
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight neighbours, clockwise from up.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its cells in row-major order.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses one row per non-empty line, mapping each character to a cell.
+    /// Characters mapped to `None` and rows of differing lengths are errors.
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in text.lines().filter(|l| !l.is_empty()) {
+            let before = cells.len();
+            for (k, c) in line.char_indices() {
+                let Some(value) = cell(c) else {
+                    let fragment = &line[k..k + c.len_utf8()];
+                    return Err(Error::new(format!("unexpected {c:?} in grid")).at(text, fragment));
+                };
+                cells.push(value);
+            }
+            let row = cells.len() - before;
+            match width {
+                None => width = Some(row),
+                Some(width) if row != width => {
+                    return Err(
+                        Error::new(format!("row of {row} cells in a grid {width} wide"))
+                            .at(text, line),
+                    );
+                }
+                Some(_) => (),
+            }
+        }
+        let width = width.unwrap_or(0);
+        Ok(Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Bounds-checks signed coordinates.
+    pub fn position(&self, x: isize, y: isize) -> Option<Pos> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// The position `delta` away from `pos`, if it is inside the grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        self.position(x as isize + dx, y as isize + dy)
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// The cell at a signed point, if it is inside the grid.
+    pub fn at(&self, point: Point) -> Option<&T> {
+        point.to_pos().and_then(|p| self.get(p))
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The four orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The up to eight neighbours of `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Positions from `pos` (included) in steps of `delta` up to the edge.
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.get(pos).map(|_| pos), move |p| self.step(*p, delta))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(p, _)| p)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.ray((x, 0), (0, 1)).map(|p| &self[p]))
+    }
+
+    /// Lines running down and to the right, starting from the left and top
+    /// edges.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+        starts.map(move |p| self.ray(p, (1, 1)).map(|p| &self[p]))
+    }
+
+    /// Lines running down and to the left, starting from the top and right
+    /// edges.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let right = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(move |y| (right, y)));
+        starts.map(move |p| self.ray(p, (-1, 1)).map(|p| &self[p]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders one line per row, without a trailing newline.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse_and_render() {
+        let grid = Grid::parse(TEXT, Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.find(&'f'), Some((2, 1)));
+        assert_eq!(grid.to_string(), TEXT.trim_end());
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("ab\nabc\n", Some).unwrap_err();
+        assert_eq!(err.position, Some((2, 1)));
+        let err = Grid::parse("..\n.#\n", |c| (c == '.').then_some(0)).unwrap_err();
+        assert_eq!(err.position, Some((2, 2)));
+        assert_eq!(err.message, "unexpected '#' in grid");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::parse(TEXT, Some).unwrap();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn lines() {
+        let grid = Grid::parse(TEXT, Some).unwrap();
+        let collect = |line: &mut dyn Iterator<Item = &char>| line.collect::<String>();
+        assert_eq!(
+            grid.rows()
+                .map(|r| r.iter().collect())
+                .collect::<Vec<String>>(),
+            ["abc", "def"]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+}
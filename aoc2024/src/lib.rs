@@ -0,0 +1,182 @@
+pub use aoc_common::error;
+pub use aoc_common::{debug, info, trace};
+
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod memo;
+pub mod parse;
+pub mod search;
+pub mod solution;
+
+use solution::{Day, Year};
+
+pub const YEAR: &str = "2024";
+
+macro_rules! days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        /// Every day of the year, in order.
+        pub static DAYS: &[Day] = &[$(Day::of::<$module::$solution>(YEAR)),*];
+    };
+}
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+days! {
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day17::Day17,
+    day18::Day18,
+    day19::Day19,
+    day20::Day20,
+    day21::Day21,
+    day22::Day22,
+    day23::Day23,
+    day24::Day24,
+    day25::Day25,
+}
+
+pub static PUZZLES: Year = Year {
+    year: YEAR,
+    days: DAYS,
+    answers: include_str!("../answers.txt"),
+};
+
+pub fn get_day(day: u8) -> Option<&'static Day> {
+    PUZZLES.get_day(day)
+}
+
+/// One test per line of the answers file, generated by `build.rs`.
+#[cfg(test)]
+mod answers {
+    include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::Answers;
+    use aoc_common::differential::{self, DEFAULT_SIZE};
+    use aoc_common::solution::{Params, Solution};
+
+    #[test]
+    fn registry_is_ordered() {
+        for (k, day) in DAYS.iter().enumerate() {
+            assert_eq!(usize::from(day.day), k + 1);
+        }
+    }
+
+    #[test]
+    fn bundled_answers_parse() {
+        assert!(Answers::parse(PUZZLES.answers, "answers.txt").is_ok());
+    }
+
+    /// Days without a generator, such as one fresh from `new`, are skipped.
+    #[test]
+    fn generated_inputs_solve() {
+        let params = Params::new();
+        for day in DAYS {
+            for seed in 0..3 {
+                let Some(input) = day.generate(seed, 20) else {
+                    continue;
+                };
+                let context = format!("day {} seed {seed}", day.day);
+                assert_eq!(day.validate(&input, &params), vec![], "{context}");
+                let parsed = day.parse(&input).expect(&context);
+                for part in 1..=day.parts {
+                    assert!(
+                        day.solve(&parsed, part, &params).is_ok(),
+                        "{context} part {part}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day05_orders_updates_that_take_more_than_two_passes() {
+        let input = "11|22\n11|33\n11|44\n11|55\n22|33\n33|44\n22|44\n44|55\n22|55\n33|55\n\n\
+                     55,44,33,22,11\n";
+        let parsed = day05::Day05::parse(input).unwrap();
+        assert_eq!(day05::Day05::part_2(&parsed, &Params::new()).unwrap(), 33);
+    }
+
+    #[test]
+    fn day05_rejects_updates_the_rules_leave_open() {
+        let parsed = day05::Day05::parse("11|22\n22|33\n\n22,11,44\n").unwrap();
+        assert!(day05::Day05::part_2(&parsed, &Params::new()).is_err());
+    }
+
+    #[test]
+    fn day14_finds_a_picture_within_the_first_height_seconds() {
+        // The four robots meet at 3,2 after 5 seconds, before the y cycle
+        // of 7 seconds first comes round again.
+        let input = "p=9,6 v=1,2\np=2,4 v=-2,1\np=10,0 v=3,-1\np=4,1 v=2,3\n";
+        let params = Params::new().with("width", 11).with("height", 7);
+        let parsed = day14::Day14::parse(input).unwrap();
+        assert_eq!(day14::Day14::part_2(&parsed, &params).unwrap(), 5);
+    }
+
+    #[test]
+    fn day23_reference_reports_tied_lan_parties() {
+        let parsed = day23::Day23::parse("aa-bb\nbb-cc\ncc-aa\ndd-ee\nee-ff\nff-dd\n").unwrap();
+        let reference = day23::Day23::reference_2(&parsed, &Params::new()).unwrap();
+        assert!(reference.is_err());
+        let parsed = day23::Day23::parse("aa-bb\nbb-cc\ncc-aa\ndd-ee\n").unwrap();
+        let reference = day23::Day23::reference_2(&parsed, &Params::new()).unwrap();
+        assert_eq!(reference.unwrap(), "aa,bb,cc");
+    }
+
+    #[test]
+    fn day21_rejects_codes_too_long_for_a_number() {
+        let err = day21::Day21::parse("029A\n123456789012345678901234A\n").unwrap_err();
+        assert_eq!(err.position, Some((2, 1)));
+    }
+
+    #[test]
+    fn solvers_agree_with_references() {
+        for day in DAYS {
+            if let Err(mismatch) = differential::check(day, 0..10, DEFAULT_SIZE, &Params::new()) {
+                panic!("day {}: {mismatch}", day.day);
+            }
+        }
+    }
+}
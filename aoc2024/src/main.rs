@@ -1,3 +1,9 @@
-fn main() {
-    println!("Hello, world!");
+use std::process::ExitCode;
+
+use aoc2024::solution::Year;
+
+static YEARS: &[&Year] = &[&aoc2024::PUZZLES];
+
+fn main() -> ExitCode {
+    aoc_common::runner::main(YEARS)
 }
@@ -0,0 +1,119 @@
+//! Memo tables for recursive solvers, owned by a single solver call so that
+//! nothing carries over from one input to the next.
+//!
+//! A recursive function takes the table along and checks it first:
+//!
+//! ```ignore
+//! fn count(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     if let Some(&count) = memo.get(&n) {
+//!         return count;
+//!     }
+//!     let count = ...;
+//!     memo.insert(n, count)
+//! }
+//! ```
+//!
+//! Lookups take any borrowed form of the key, so a table keyed by slices of
+//! the input needs no allocation. The hit and miss counts are logged at the
+//! trace level when the table is dropped.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::trace;
+
+pub struct Memo<K, V> {
+    name: &'static str,
+    table: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Eq + Hash, V> Memo<K, V> {
+    /// An empty table, named in the trace output.
+    pub fn new(name: &'static str) -> Memo<K, V> {
+        Memo {
+            name,
+            table: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let value = self.table.get(key);
+        match value {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+        value
+    }
+
+    /// Stores `value` for `key` and hands it back.
+    pub fn insert(&mut self, key: K, value: V) -> V
+    where
+        V: Clone,
+    {
+        self.table.insert(key, value.clone());
+        value
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        trace!(
+            "memo table",
+            name = self.name,
+            hits = self.hits,
+            misses = self.misses,
+            entries = self.table.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        if let Some(&value) = memo.get(&n) {
+            return value;
+        }
+        let value = fibonacci(n - 1, memo) + fibonacci(n - 2, memo);
+        memo.insert(n, value)
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut memo = Memo::new("fibonacci");
+        assert_eq!(fibonacci(50, &mut memo), 12_586_269_025);
+        // Each of 2..=50 is computed once and then found once more, but for
+        // the last two.
+        assert_eq!(memo.misses(), 49);
+        assert_eq!(memo.hits(), 47);
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let mut memo: Memo<String, usize> = Memo::new("lengths");
+        memo.insert("towel".to_string(), 5);
+        assert_eq!(memo.get("towel"), Some(&5));
+        assert_eq!(memo.get("rug"), None);
+    }
+}
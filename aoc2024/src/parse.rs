@@ -0,0 +1,150 @@
+//! Helpers for the input shapes that recur across days. Each takes the whole
+//! `input` along with the part of it being parsed, so that errors point at
+//! the offending text.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::{Error, Result, fail_at, parse_at};
+
+/// The non-empty lines of `text`.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().filter(|line| !line.is_empty())
+}
+
+/// The blocks of `text` separated by blank lines.
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// Exactly two blocks, e.g. the rules and the updates of day 05.
+pub fn two_blocks<'a>(input: &str, text: &'a str) -> Result<(&'a str, &'a str)> {
+    let message = "expected two blocks separated by a blank line";
+    let mut blocks = blocks(text);
+    match (blocks.next(), blocks.next(), blocks.next()) {
+        (Some(first), Some(second), None) => Ok((first, second)),
+        (_, _, Some(extra)) => fail_at(input, extra, message),
+        _ => Err(Error::new(message)),
+    }
+}
+
+/// Every integer in `text`, minus sign included, ignoring whatever surrounds
+/// them, e.g. `[94, 34]` from `Button A: X+94, Y+34`.
+pub fn integers<T>(input: &str, text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut k = 0;
+    while k < bytes.len() {
+        if !bytes[k].is_ascii_digit() {
+            k += 1;
+            continue;
+        }
+        let start = if k > 0 && bytes[k - 1] == b'-' {
+            k - 1
+        } else {
+            k
+        };
+        while k < bytes.len() && bytes[k].is_ascii_digit() {
+            k += 1;
+        }
+        numbers.push(parse_at(input, &text[start..k])?);
+    }
+    Ok(numbers)
+}
+
+/// The values of `text` separated by `separator`, e.g. `0,3,5,4`.
+pub fn list<T>(input: &str, text: &str, separator: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.split(separator)
+        .map(|field| parse_at(input, field))
+        .collect()
+}
+
+/// The values of `text` separated by any amount of whitespace.
+pub fn words<T>(input: &str, text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.split_whitespace()
+        .map(|field| parse_at(input, field))
+        .collect()
+}
+
+/// `text` split around the first `separator`, failing with what was
+/// `expected` when there is none.
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    separator: &str,
+    expected: &str,
+) -> Result<(&'a str, &'a str)> {
+    match text.split_once(separator) {
+        Some(parts) => Ok(parts),
+        None => fail_at(input, text, format!("expected {expected}")),
+    }
+}
+
+/// The value of the `key: value` line of `text` for `key`.
+pub fn field<'a>(text: &'a str, key: &str) -> Result<&'a str> {
+    lines(text)
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix(": "))
+        .ok_or_else(|| Error::new(format!("missing {key:?} line")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_and_lines() {
+        let input = "a\nb\n\n\nc\n\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), ["a\nb", "c"]);
+        assert_eq!(lines(input).collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(two_blocks(input, input).unwrap(), ("a\nb", "c"));
+
+        let err = two_blocks("a\n\nb\n\nc\n", "a\n\nb\n\nc\n").unwrap_err();
+        assert_eq!(err.position, Some((5, 1)));
+        assert!(two_blocks("a\nb\n", "a\nb\n").is_err());
+    }
+
+    #[test]
+    fn integers_in_text() {
+        let input = "Button A: X+94, Y-34\np=0,4 v=3,-3";
+        assert_eq!(
+            integers::<i64>(input, input).unwrap(),
+            [94, -34, 0, 4, 3, -3]
+        );
+        assert!(integers::<i64>("x", "").unwrap().is_empty());
+
+        let input = "v=300";
+        let err = integers::<u8>(input, input).unwrap_err();
+        assert_eq!(err.position, Some((1, 3)));
+    }
+
+    #[test]
+    fn separated_values() {
+        let input = "Register A: 729\n\nProgram: 0,1,5,4\n";
+        assert_eq!(field(input, "Register A").unwrap(), "729");
+        let program = field(input, "Program").unwrap();
+        assert_eq!(list::<u8>(input, program, ",").unwrap(), [0, 1, 5, 4]);
+        assert!(field(input, "Register B").is_err());
+
+        let input = "3   4\n4 x";
+        assert_eq!(words::<u8>(input, "3   4").unwrap(), [3, 4]);
+        let err = words::<u8>(input, &input[6..]).unwrap_err();
+        assert_eq!(err.position, Some((2, 3)));
+
+        let err = split_once(input, &input[6..], ": ", "an equation").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected an equation");
+    }
+}
@@ -0,0 +1,242 @@
+//! Shortest-path searches over implicit graphs given by a neighbour function.
+//!
+//! Every search records the best cost of each node it reaches along with all
+//! of its predecessors on optimal paths. Once a goal is reached the search
+//! keeps going until nothing cheaper is left, so every goal at the optimal
+//! cost is found. Edge costs must be positive.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub struct Search<N> {
+    start: N,
+    costs: HashMap<N, u64>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start: N) -> Search<N> {
+        Search {
+            costs: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+            start,
+        }
+    }
+
+    /// Records reaching `next` from `node` at `cost`, returning whether it
+    /// is the best way there so far and `next` needs (re)visiting.
+    fn relax(&mut self, node: &N, next: N, cost: u64) -> bool {
+        match self.costs.get(&next) {
+            Some(&best) if best < cost => false,
+            Some(&best) if best == cost => {
+                if let Some(predecessors) = self.predecessors.get_mut(&next) {
+                    predecessors.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.costs.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+
+    /// The first goal reached, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goals.first()
+    }
+
+    /// Every goal reached at the optimal cost.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The optimal cost of reaching a goal.
+    pub fn cost(&self) -> Option<u64> {
+        self.goal().and_then(|goal| self.cost_to(goal))
+    }
+
+    pub fn cost_to(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// An optimal path from the start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goal().and_then(|goal| self.path_to(goal))
+    }
+
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        let mut current = node;
+        while *current != self.start {
+            current = &self.predecessors[current][0];
+            path.push(current.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on any optimal path to any of the goals.
+    pub fn on_optimal_paths(&self) -> HashSet<N> {
+        let mut nodes: HashSet<N> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(node) = stack.pop() {
+            for predecessor in self.predecessors.get(&node).into_iter().flatten() {
+                if nodes.insert(predecessor.clone()) {
+                    stack.push(predecessor.clone());
+                }
+            }
+        }
+        nodes
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut best = None;
+    while let Some((node, cost)) = queue.pop_front() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if is_goal(&node) {
+            best = Some(cost);
+            search.goals.push(node);
+            continue;
+        }
+        for next in neighbours(&node) {
+            if search.relax(&node, next.clone(), cost + 1) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm over weighted edges.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// A* search; `heuristic` must never overestimate the remaining cost, and
+/// never drop by more than an edge's cost along it.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new(start.clone());
+    // The heap holds indices into `nodes`, so nodes need not be ordered.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut nodes = vec![start];
+    let mut best = None;
+    while let Some(Reverse((estimate, cost, index))) = heap.pop() {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        let node = nodes[index].clone();
+        if search.costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            best = Some(cost);
+            search.goals.push(node);
+            continue;
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if search.relax(&node, next.clone(), next_cost) {
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open(grid: &Grid<char>, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours()
+            .filter(|n| grid.at(*n).is_some_and(|c| *c != '#'))
+    }
+
+    #[test]
+    fn searches_agree_on_the_shortest_path() {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let start = Point::from(grid.find(&'S').unwrap());
+        let end = Point::from(grid.find(&'E').unwrap());
+
+        let by_bfs = bfs(start, |p| open(&grid, *p), |p| *p == end);
+        let by_dijkstra = dijkstra(start, |p| open(&grid, *p).map(|n| (n, 1)), |p| *p == end);
+        let by_astar = astar(
+            start,
+            |p| open(&grid, *p).map(|n| (n, 1)),
+            |p| p.manhattan(end) as u64,
+            |p| *p == end,
+        );
+
+        for search in [&by_bfs, &by_dijkstra, &by_astar] {
+            assert_eq!(search.cost(), Some(5));
+            let path = search.path().unwrap();
+            assert_eq!((path[0], path[5]), (start, end));
+        }
+        // Three routes of length 5 between them cover all ten open tiles.
+        assert_eq!(by_bfs.on_optimal_paths().len(), 10);
+        assert_eq!(by_dijkstra.on_optimal_paths(), by_bfs.on_optimal_paths());
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let search = bfs(
+            0_u8,
+            |n| [n + 1].into_iter().filter(|n| *n < 5),
+            |n| *n == 9,
+        );
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.path(), None);
+        assert_eq!(search.cost_to(&4), Some(4));
+    }
+}
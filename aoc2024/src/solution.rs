@@ -0,0 +1,13 @@
+//! The shared solution framework from `aoc-common`, with the test and binary
+//! helpers bound to this year.
+
+pub use aoc_common::solution::{Answer, Day, Params, Parsed, Solution, Year};
+
+/// Checks one line of the answers file; see `build.rs`.
+pub fn check_entry(line: &str) {
+    aoc_common::solution::check_entry(&crate::PUZZLES, line);
+}
+
+pub fn main<S: Solution>() {
+    aoc_common::solution::main::<S>(crate::YEAR);
+}
@@ -1,3 +1,13 @@
-fn main() {
-    println!("Hello, world!");
+//! Workspace entry point dispatching to every year's crate, e.g.
+//! `cargo run -- run 2024 06`.
+
+use std::process::ExitCode;
+
+use aoc_common::solution::Year;
+
+/// Every year in the workspace, in order.
+static YEARS: &[&Year] = &[&aoc2024::PUZZLES];
+
+fn main() -> ExitCode {
+    aoc_common::runner::main(YEARS)
 }
@@ -0,0 +1,37 @@
+//! Generates a test for every line of the answers file; see
+//! `aoc_common::answers::generate_tests`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::answers::{self, Answers};
+use aoc_common::input;
+
+fn main() {
+    let year = env!("CARGO_PKG_NAME").trim_start_matches("aoc");
+    let bundled = PathBuf::from("answers.txt");
+    println!("cargo::rerun-if-changed={}", bundled.display());
+    println!("cargo::rerun-if-env-changed={}", input::ENV_VAR);
+
+    // Like `Answers::load`, a copy in the input tree takes precedence. Its
+    // directory is watched so that creating the copy regenerates the tests.
+    let local = answers::path(year);
+    let watched = [local.parent().unwrap(), &input::root()]
+        .into_iter()
+        .find(|dir| dir.is_dir())
+        .map(Path::to_path_buf);
+    if let Some(dir) = watched {
+        println!("cargo::rerun-if-changed={}", dir.display());
+    }
+    let source = if local.is_file() { &local } else { &bundled };
+
+    let text = fs::read_to_string(source)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", source.display()));
+    let answers =
+        Answers::parse(&text, &source.display().to_string()).unwrap_or_else(|err| panic!("{err}"));
+    let tests = answers::generate_tests(&answers, "crate::solution::check_entry");
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(&out, tests).unwrap_or_else(|err| panic!("could not write {}: {err}", out.display()));
+}
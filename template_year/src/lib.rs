@@ -0,0 +1,34 @@
+pub use aoc_common::error;
+pub use aoc_common::{debug, info, trace};
+
+pub mod solution;
+
+use solution::{Day, Year};
+
+pub const YEAR: &str = "YYYY";
+
+macro_rules! days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        /// Every day of the year, in order.
+        pub static DAYS: &[Day] = &[$(Day::of::<$module::$solution>(YEAR)),*];
+    };
+}
+
+days! {
+}
+
+pub static PUZZLES: Year = Year {
+    year: YEAR,
+    days: DAYS,
+    answers: include_str!("../answers.txt"),
+};
+
+pub fn get_day(day: u8) -> Option<&'static Day> {
+    PUZZLES.get_day(day)
+}
+
+/// One test per line of the answers file, generated by `build.rs`.
+#[cfg(test)]
+mod answers {
+    include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+}
@@ -0,0 +1,9 @@
+use std::process::ExitCode;
+
+use aocYYYY::solution::Year;
+
+static YEARS: &[&Year] = &[&aocYYYY::PUZZLES];
+
+fn main() -> ExitCode {
+    aoc_common::runner::main(YEARS)
+}